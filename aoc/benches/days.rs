@@ -0,0 +1,33 @@
+use aoc::days::DAYS;
+use aoc_core::Part;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+/// Every day is measured against the same made up input, so results can be compared between
+/// machines without sharing the real inputs
+const SEED: u64 = 2023;
+
+/// Parsing and each part of every day, measured separately against a made up input
+fn days(c: &mut Criterion) {
+    for day in DAYS {
+        let input_text = day.generator.input(SEED);
+        let solution = match (day.parse)(&input_text) {
+            Ok(solution) => solution,
+            Err(e) => {
+                eprintln!("Skipping {}, couldn't parse its input: {}", day.name(), e);
+                continue;
+            }
+        };
+
+        let mut group = c.benchmark_group(day.name());
+        group.bench_function("parse", |b| b.iter(|| (day.parse)(black_box(&input_text))));
+        for part in Part::BOTH {
+            group.bench_function(format!("part_{}", part), |b| {
+                b.iter(|| solution.answer(black_box(part)))
+            });
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
@@ -0,0 +1,100 @@
+use aoc_core::Part;
+use std::{collections::BTreeMap, path::Path};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum AnswersError {
+    #[error("couldn't read {path}: {source}")]
+    Read {
+        path: String,
+        source: std::io::Error,
+    },
+    #[error(transparent)]
+    Toml(#[from] toml::de::Error),
+    #[error("'{0}' is not a day such as `day_07`")]
+    InvalidDay(String),
+    #[error("'{0}' is not a part such as `part_1`")]
+    InvalidPart(String),
+    #[error("the answer to {0} {1} should be a number or a string")]
+    InvalidAnswer(String, String),
+}
+
+/// The accepted answers for each part of each day, as recorded in `answers.toml`
+///
+/// # Examples
+///
+/// ```
+/// use aoc::answers::Answers;
+/// use aoc_core::Part;
+///
+/// let answers = Answers::parse("[day_07]\npart_1 = 6440\npart_2 = \"5905\"").unwrap();
+/// assert_eq!(answers.get(7, Part::One), Some("6440"));
+/// assert_eq!(answers.get(7, Part::Two), Some("5905"));
+/// assert_eq!(answers.get(8, Part::One), None);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers(BTreeMap<(u8, Part), String>);
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self, AnswersError> {
+        let text = std::fs::read_to_string(path).map_err(|source| AnswersError::Read {
+            path: path.display().to_string(),
+            source,
+        })?;
+        Answers::parse(&text)
+    }
+
+    pub fn parse(text: &str) -> Result<Self, AnswersError> {
+        let table: BTreeMap<String, BTreeMap<String, toml::Value>> = toml::from_str(text)?;
+        let mut answers = BTreeMap::new();
+        for (day_name, parts) in table {
+            let day = day_name
+                .strip_prefix("day_")
+                .and_then(|day| day.parse::<u8>().ok())
+                .ok_or_else(|| AnswersError::InvalidDay(day_name.clone()))?;
+            for (part_name, value) in parts {
+                let part = part_name
+                    .strip_prefix("part_")
+                    .and_then(|part| part.parse().ok())
+                    .and_then(Part::from_number)
+                    .ok_or_else(|| AnswersError::InvalidPart(part_name.clone()))?;
+                let answer = match value {
+                    toml::Value::Integer(answer) => answer.to_string(),
+                    toml::Value::String(answer) => answer,
+                    _ => return Err(AnswersError::InvalidAnswer(day_name, part_name)),
+                };
+                answers.insert((day, part), answer);
+            }
+        }
+        Ok(Self(answers))
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.0.get(&(day, part)).map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(matches!(
+            Answers::parse("[day_x]\npart_1 = 1"),
+            Err(AnswersError::InvalidDay(day)) if day == "day_x"
+        ));
+        assert!(matches!(
+            Answers::parse("[day_01]\npart_3 = 1"),
+            Err(AnswersError::InvalidPart(part)) if part == "part_3"
+        ));
+        assert!(matches!(
+            Answers::parse("[day_01]\npart_1 = [1]"),
+            Err(AnswersError::InvalidAnswer(..))
+        ));
+        assert!(matches!(
+            Answers::parse("day_01 ="),
+            Err(AnswersError::Toml(_))
+        ));
+    }
+}
@@ -0,0 +1,100 @@
+use aoc_core::{parse_dyn, DynError, DynSolution, Examples, Generator};
+use std::path::PathBuf;
+
+/// A solved day, dispatching into that day's [`aoc_core::Solution`]
+pub struct Day {
+    pub number: u8,
+    pub parse: fn(&str) -> Result<Box<dyn DynSolution>, DynError>,
+    /// The examples from the puzzle description, for `aoc run --example`
+    pub examples: Examples,
+    /// Makes up inputs for the day, for `aoc generate` and the benchmarks
+    pub generator: Generator,
+}
+
+impl Day {
+    /// The crate directory of the day, e.g. `day_07`
+    pub fn name(&self) -> String {
+        format!("day_{:02}", self.number)
+    }
+}
+
+/// The recorded answers, checked by `aoc verify`
+pub fn answers_path() -> PathBuf {
+    workspace_root().join("answers.toml")
+}
+
+pub fn workspace_root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("The runner lives inside the workspace")
+        .to_path_buf()
+}
+
+pub const DAYS: &[Day] = &[
+    Day {
+        number: 1,
+        parse: parse_dyn::<day_01::Day01>,
+        examples: day_01::EXAMPLES,
+        generator: day_01::GENERATOR,
+    },
+    Day {
+        number: 2,
+        parse: parse_dyn::<day_02::Day02>,
+        examples: day_02::EXAMPLES,
+        generator: day_02::GENERATOR,
+    },
+    Day {
+        number: 3,
+        parse: parse_dyn::<day_03::Day03>,
+        examples: day_03::EXAMPLES,
+        generator: day_03::GENERATOR,
+    },
+    Day {
+        number: 4,
+        parse: parse_dyn::<day_04::Day04>,
+        examples: day_04::EXAMPLES,
+        generator: day_04::GENERATOR,
+    },
+    Day {
+        number: 5,
+        parse: parse_dyn::<day_05::Day05>,
+        examples: day_05::EXAMPLES,
+        generator: day_05::GENERATOR,
+    },
+    Day {
+        number: 6,
+        parse: parse_dyn::<day_06::Day06>,
+        examples: day_06::EXAMPLES,
+        generator: day_06::GENERATOR,
+    },
+    Day {
+        number: 7,
+        parse: parse_dyn::<day_07::Day07>,
+        examples: day_07::EXAMPLES,
+        generator: day_07::GENERATOR,
+    },
+    Day {
+        number: 8,
+        parse: parse_dyn::<day_08::Day08>,
+        examples: day_08::EXAMPLES,
+        generator: day_08::GENERATOR,
+    },
+    Day {
+        number: 9,
+        parse: parse_dyn::<day_09::Day09>,
+        examples: day_09::EXAMPLES,
+        generator: day_09::GENERATOR,
+    },
+    Day {
+        number: 10,
+        parse: parse_dyn::<day_10::Day10>,
+        examples: day_10::EXAMPLES,
+        generator: day_10::GENERATOR,
+    },
+    Day {
+        number: 11,
+        parse: parse_dyn::<day_11::Day11>,
+        examples: day_11::EXAMPLES,
+        generator: day_11::GENERATOR,
+    },
+];
@@ -0,0 +1,363 @@
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ExtractError {
+    #[error("couldn't find any examples in the page")]
+    NoExamples,
+    #[error("{0} already exists, pass --force to replace it")]
+    Exists(String),
+    #[error("couldn't find the examples! macro in {0}")]
+    NoMacro(String),
+    #[error("couldn't access {path}: {source}")]
+    Io {
+        path: String,
+        source: std::io::Error,
+    },
+}
+
+/// An example found in a puzzle page, along with the answers the page gives for it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PageExample {
+    pub name: String,
+    pub text: String,
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+/// Finds the examples in a saved puzzle page. Each part's description is an `<article>`, where
+/// the example is its first `<pre><code>` block and the answer is the last emphasized
+/// `<code><em>` in it. A part without a block of its own is answered with the previous example.
+///
+/// # Examples
+///
+/// ```
+/// use aoc::extract::extract;
+///
+/// let page = "<article class=\"day-desc\"><pre><code>1 &lt; 2\n</code></pre>\
+///     <p>So the answer is <code><em>3</em></code>.</p></article>\
+///     <article class=\"day-desc\"><p>Now it's <code><em>4</em></code>.</p></article>";
+/// let examples = extract(page);
+/// assert_eq!(examples.len(), 1);
+/// assert_eq!(examples[0].text, "1 < 2\n");
+/// assert_eq!(examples[0].part_one.as_deref(), Some("3"));
+/// assert_eq!(examples[0].part_two.as_deref(), Some("4"));
+/// ```
+pub fn extract(page: &str) -> Vec<PageExample> {
+    let mut examples: Vec<PageExample> = Vec::new();
+    for (i, article) in articles(page).into_iter().take(2).enumerate() {
+        if let Some(text) = code_blocks(article).into_iter().next() {
+            examples.push(PageExample {
+                name: String::new(),
+                text,
+                part_one: None,
+                part_two: None,
+            });
+        }
+        let (Some(example), Some(answer)) = (examples.last_mut(), answers(article).pop()) else {
+            continue;
+        };
+        match i {
+            0 => example.part_one = Some(answer),
+            _ => example.part_two = Some(answer),
+        }
+    }
+
+    let count = examples.len();
+    for (i, example) in examples.iter_mut().enumerate() {
+        example.name = match count {
+            1 => "example".to_string(),
+            _ => format!("example_{}", i + 1),
+        };
+    }
+    examples
+}
+
+/// The text of each `<article>`, or the whole page if it doesn't have any
+fn articles(page: &str) -> Vec<&str> {
+    let articles = between(page, "<article", "</article>");
+    match articles.is_empty() {
+        true => vec![page],
+        false => articles,
+    }
+}
+
+/// The contents of each `<pre><code>` block, without any markup
+fn code_blocks(article: &str) -> Vec<String> {
+    between(article, "<pre><code>", "</code></pre>")
+        .into_iter()
+        .map(|block| {
+            let text = unescape(block);
+            match text.ends_with('\n') {
+                true => text,
+                false => text + "\n",
+            }
+        })
+        .collect()
+}
+
+/// The contents of each `<code><em>`, which is how the pages highlight an example's answer
+fn answers(article: &str) -> Vec<String> {
+    between(article, "<code><em>", "</em></code>")
+        .into_iter()
+        .map(|answer| unescape(answer).trim().to_string())
+        .collect()
+}
+
+/// Every slice of `text` that starts after `start` and ends before the next `end`
+fn between<'a>(text: &'a str, start: &str, end: &str) -> Vec<&'a str> {
+    let mut found = Vec::new();
+    let mut rest = text;
+    while let Some(i) = rest.find(start) {
+        let inner = &rest[i + start.len()..];
+        let Some(j) = inner.find(end) else {
+            break;
+        };
+        found.push(&inner[..j]);
+        rest = &inner[j + end.len()..];
+    }
+    found
+}
+
+/// Strips the tags out of the html, and decodes the entities the pages use
+fn unescape(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Renders the answers the same way as `examples/answers.toml`
+pub fn render_answers(examples: &[PageExample]) -> String {
+    let tables: Vec<String> = examples
+        .iter()
+        .map(|example| {
+            let mut table = format!("[{}]\n", example.name);
+            for (key, answer) in [("part_1", &example.part_one), ("part_2", &example.part_two)] {
+                table += &match answer {
+                    Some(answer) if answer.parse::<i64>().is_ok() => {
+                        format!("{} = {}\n", key, answer)
+                    }
+                    Some(answer) => format!("{} = {:?}\n", key, answer),
+                    None => format!("# {} = \n", key),
+                };
+            }
+            table
+        })
+        .collect();
+    tables.join("\n")
+}
+
+/// Points the day's `examples!` at the given examples
+///
+/// # Examples
+///
+/// ```
+/// use aoc::extract::set_example_names;
+///
+/// let lib = "pub const EXAMPLES: aoc_core::Examples = aoc_core::examples![\"example\"];\n";
+/// assert_eq!(
+///     set_example_names(lib, &["example_1", "example_2"]).unwrap(),
+///     "pub const EXAMPLES: aoc_core::Examples = aoc_core::examples![\"example_1\", \"example_2\"];\n"
+/// );
+/// ```
+pub fn set_example_names(lib: &str, names: &[&str]) -> Option<String> {
+    let start = lib.find("examples![")? + "examples![".len();
+    let end = start + lib[start..].find(']')?;
+    let names: Vec<String> = names.iter().map(|name| format!("{:?}", name)).collect();
+    Some(format!(
+        "{}{}{}",
+        &lib[..start],
+        names.join(", "),
+        &lib[end..]
+    ))
+}
+
+/// Writes the examples into the crate's `examples` directory and points its `examples!` at them,
+/// returning every file it touched. Existing examples are only replaced when `force` is set, and
+/// any that the page no longer has are deleted so they aren't loaded as stale fixtures.
+pub fn write(
+    crate_dir: &Path,
+    examples: &[PageExample],
+    force: bool,
+) -> Result<Vec<PathBuf>, ExtractError> {
+    if examples.is_empty() {
+        return Err(ExtractError::NoExamples);
+    }
+    let dir = crate_dir.join("examples");
+    let answers_path = dir.join("answers.toml");
+    if !force {
+        let answered = std::fs::read_to_string(&answers_path)
+            .is_ok_and(|text| text.lines().any(|line| line.starts_with("part_")));
+        if answered {
+            return Err(ExtractError::Exists(answers_path.display().to_string()));
+        }
+    }
+
+    std::fs::create_dir_all(&dir).map_err(|source| io_error(&dir, source))?;
+    let mut touched = remove_examples(&dir)?;
+    for example in examples {
+        let path = dir.join(format!("{}.txt", example.name));
+        std::fs::write(&path, &example.text).map_err(|source| io_error(&path, source))?;
+        touched.push(path);
+    }
+    std::fs::write(&answers_path, render_answers(examples))
+        .map_err(|source| io_error(&answers_path, source))?;
+    touched.push(answers_path);
+
+    let lib_path = crate_dir.join("src").join("lib.rs");
+    let lib = std::fs::read_to_string(&lib_path).map_err(|source| io_error(&lib_path, source))?;
+    let names: Vec<&str> = examples
+        .iter()
+        .map(|example| example.name.as_str())
+        .collect();
+    let lib = set_example_names(&lib, &names)
+        .ok_or_else(|| ExtractError::NoMacro(lib_path.display().to_string()))?;
+    std::fs::write(&lib_path, lib).map_err(|source| io_error(&lib_path, source))?;
+    touched.push(lib_path);
+    Ok(touched)
+}
+
+/// Deletes the extracted examples in the directory, as in `example.txt` and `example_2.txt`, and
+/// returns their paths
+fn remove_examples(dir: &Path) -> Result<Vec<PathBuf>, ExtractError> {
+    let entries = std::fs::read_dir(dir).map_err(|source| io_error(dir, source))?;
+    let mut removed = Vec::new();
+    for entry in entries {
+        let path = entry.map_err(|source| io_error(dir, source))?.path();
+        let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        let extracted = name.strip_suffix(".txt").is_some_and(|stem| {
+            stem == "example"
+                || stem
+                    .strip_prefix("example_")
+                    .is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+        });
+        if extracted {
+            std::fs::remove_file(&path).map_err(|source| io_error(&path, source))?;
+            removed.push(path);
+        }
+    }
+    Ok(removed)
+}
+
+fn io_error(path: &Path, source: std::io::Error) -> ExtractError {
+    ExtractError::Io {
+        path: path.display().to_string(),
+        source,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<!DOCTYPE html>
+<html lang="en-us"><body><main>
+<article class="day-desc"><h2>--- Day 8: Haunted Wasteland ---</h2>
+<p>For example:</p>
+<pre><code>RL
+
+AAA = (BBB, CCC)
+ZZZ = (ZZZ, ZZZ)
+</code></pre>
+<p>Starting with <code>AAA</code>, you need to look up the next element, taking <code><em>2</em></code> steps.</p>
+</article>
+<p>Your puzzle answer was <code>21389</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<pre><code>LR
+
+11A = (11B, XXX)
+<em>11Z</em> = (11B, XXX)
+</code></pre>
+<p>So, in this example, you end up entirely on nodes that end in <code>Z</code> after <code><em>6</em></code> steps.</p>
+</article>
+</main></body></html>"#;
+
+    #[test]
+    fn test_extract() {
+        let examples = extract(PAGE);
+        assert_eq!(
+            examples,
+            vec![
+                PageExample {
+                    name: "example_1".to_string(),
+                    text: "RL\n\nAAA = (BBB, CCC)\nZZZ = (ZZZ, ZZZ)\n".to_string(),
+                    part_one: Some("2".to_string()),
+                    part_two: None,
+                },
+                PageExample {
+                    name: "example_2".to_string(),
+                    text: "LR\n\n11A = (11B, XXX)\n11Z = (11B, XXX)\n".to_string(),
+                    part_one: None,
+                    part_two: Some("6".to_string()),
+                },
+            ]
+        );
+        assert_eq!(
+            render_answers(&examples),
+            "[example_1]\npart_1 = 2\n# part_2 = \n\n[example_2]\n# part_1 = \npart_2 = 6\n"
+        );
+        assert!(extract("<article><p>Nothing to see</p></article>").is_empty());
+    }
+
+    #[test]
+    fn test_write() {
+        let dir = tempfile::tempdir().unwrap();
+        let lib_path = dir.path().join("src").join("lib.rs");
+        std::fs::create_dir_all(lib_path.parent().unwrap()).unwrap();
+        std::fs::write(&lib_path, "aoc_core::examples![\"example\"];\n").unwrap();
+
+        let examples = extract(PAGE);
+        let touched = write(dir.path(), &examples, false).unwrap();
+        assert_eq!(touched.len(), 4);
+        let example = std::fs::read_to_string(dir.path().join("examples/example_2.txt")).unwrap();
+        assert_eq!(example, examples[1].text);
+        assert_eq!(
+            std::fs::read_to_string(&lib_path).unwrap(),
+            "aoc_core::examples![\"example_1\", \"example_2\"];\n"
+        );
+
+        assert!(matches!(
+            write(dir.path(), &examples, false),
+            Err(ExtractError::Exists(_))
+        ));
+        assert!(write(dir.path(), &examples, true).is_ok());
+
+        // Re-extracting a page with fewer examples leaves none of the old ones behind
+        let examples_dir = dir.path().join("examples");
+        std::fs::write(examples_dir.join("example_3.txt"), "stale").unwrap();
+        std::fs::write(examples_dir.join("example_uneven.txt"), "hand written").unwrap();
+        let mut single = examples[..1].to_vec();
+        single[0].name = "example".to_string();
+        write(dir.path(), &single, true).unwrap();
+        let mut files: Vec<_> = std::fs::read_dir(&examples_dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect();
+        files.sort();
+        assert_eq!(files, ["answers.toml", "example.txt", "example_uneven.txt"]);
+        assert_eq!(
+            std::fs::read_to_string(&lib_path).unwrap(),
+            "aoc_core::examples![\"example\"];\n"
+        );
+
+        assert!(matches!(
+            write(dir.path(), &[], true),
+            Err(ExtractError::NoExamples)
+        ));
+    }
+}
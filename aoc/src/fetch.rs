@@ -0,0 +1,319 @@
+use crate::YEAR;
+use std::{
+    fmt::Debug,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use thiserror::Error;
+
+/// The environment variable holding the session cookie of a logged in browser
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const BASE_URL: &str = "https://adventofcode.com";
+/// Identifies the tool to the site, as its automation guidelines ask
+pub const USER_AGENT: &str = "github.com/TheMasonX/AdventOfCode2023";
+/// The shortest time to leave between two requests to the site, even from separate runs
+pub const MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Error)]
+pub enum FetchError {
+    #[error("no session token, set {SESSION_VAR} or save it to {0}")]
+    NoSession(String),
+    #[error("the session token was rejected, it may have expired")]
+    InvalidSession,
+    #[error("day {0:02} isn't available yet")]
+    NotAvailable(u8),
+    #[error("unexpected {status} response: {body}")]
+    Status { status: u16, body: String },
+    #[error("couldn't reach the site: {0}")]
+    Http(String),
+    #[error("couldn't access {path}: {source}")]
+    Io {
+        path: String,
+        source: std::io::Error,
+    },
+}
+
+/// The status and body of a response, whatever the status
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// The HTTP layer, so the clients can be pointed at a stand-in server
+pub trait HttpClient: Debug + Send + Sync {
+    fn get(&self, url: &str, session: &str) -> Result<Response, FetchError>;
+    fn post(&self, url: &str, session: &str, form: &[(&str, &str)])
+        -> Result<Response, FetchError>;
+}
+
+/// The real HTTP client
+#[derive(Debug)]
+pub struct UreqClient {
+    agent: ureq::Agent,
+}
+
+impl Default for UreqClient {
+    fn default() -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+}
+
+impl UreqClient {
+    fn respond(result: Result<ureq::Response, ureq::Error>) -> Result<Response, FetchError> {
+        let response = match result {
+            Ok(response) => response,
+            Err(ureq::Error::Status(_, response)) => response,
+            Err(e) => return Err(FetchError::Http(e.to_string())),
+        };
+        let status = response.status();
+        let body = response
+            .into_string()
+            .map_err(|e| FetchError::Http(e.to_string()))?;
+        Ok(Response { status, body })
+    }
+}
+
+impl HttpClient for UreqClient {
+    fn get(&self, url: &str, session: &str) -> Result<Response, FetchError> {
+        let request = self
+            .agent
+            .get(url)
+            .set("Cookie", &format!("session={}", session));
+        UreqClient::respond(request.call())
+    }
+
+    fn post(
+        &self,
+        url: &str,
+        session: &str,
+        form: &[(&str, &str)],
+    ) -> Result<Response, FetchError> {
+        let request = self
+            .agent
+            .post(url)
+            .set("Cookie", &format!("session={}", session));
+        UreqClient::respond(request.send_form(form))
+    }
+}
+
+/// Downloads puzzle inputs into the cache, never asking for one it already has
+#[derive(Debug)]
+pub struct Fetcher {
+    client: Box<dyn HttpClient>,
+    base_url: String,
+    session: Option<String>,
+    cache_dir: PathBuf,
+    min_interval: Duration,
+}
+
+impl Fetcher {
+    pub fn new(client: Box<dyn HttpClient>, base_url: &str, cache_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            client,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: None,
+            cache_dir: cache_dir.into(),
+            min_interval: MIN_INTERVAL,
+        }
+    }
+
+    /// The real site, with the session from `AOC_SESSION` or the `session` file in the cache
+    pub fn from_env(cache_dir: impl Into<PathBuf>) -> Self {
+        let fetcher = Fetcher::new(Box::<UreqClient>::default(), BASE_URL, cache_dir);
+        let session = std::env::var(SESSION_VAR)
+            .ok()
+            .or_else(|| std::fs::read_to_string(fetcher.session_path()).ok())
+            .map(|session| session.trim().to_string())
+            .filter(|session| !session.is_empty());
+        match session {
+            Some(session) => fetcher.with_session(session),
+            None => fetcher,
+        }
+    }
+
+    pub fn with_session(mut self, session: impl Into<String>) -> Self {
+        self.session = Some(session.into());
+        self
+    }
+
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    pub fn session_path(&self) -> PathBuf {
+        self.cache_dir.join("session")
+    }
+
+    /// Where the day's input is cached, which is also one of the places the input provider looks
+    pub fn cache_path(&self, day: u8) -> PathBuf {
+        self.cache_dir
+            .join(YEAR.to_string())
+            .join(format!("day_{:02}.txt", day))
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    pub fn client(&self) -> &dyn HttpClient {
+        self.client.as_ref()
+    }
+
+    pub fn session(&self) -> Result<&str, FetchError> {
+        self.session
+            .as_deref()
+            .ok_or_else(|| FetchError::NoSession(self.session_path().display().to_string()))
+    }
+
+    /// Gives the cached input, downloading it first if this is the first time it's been asked for
+    pub fn fetch_input(&self, day: u8) -> Result<String, FetchError> {
+        let path = self.cache_path(day);
+        if let Ok(text) = std::fs::read_to_string(&path) {
+            return Ok(text);
+        }
+
+        let session = self.session()?;
+        let url = format!("{}/{}/day/{}/input", self.base_url, YEAR, day);
+        self.throttle()?;
+        log::info!("Downloading {}", url);
+        let response = self.client.get(&url, session)?;
+        match response.status {
+            200 => {
+                if let Some(parent) = path.parent() {
+                    std::fs::create_dir_all(parent).map_err(|source| io_error(parent, source))?;
+                }
+                std::fs::write(&path, &response.body).map_err(|source| io_error(&path, source))?;
+                Ok(response.body)
+            }
+            404 => Err(FetchError::NotAvailable(day)),
+            400 | 401 | 500 if response.body.contains("log in") => Err(FetchError::InvalidSession),
+            status => Err(FetchError::Status {
+                status,
+                body: response.body.trim().to_string(),
+            }),
+        }
+    }
+
+    /// Waits until `min_interval` has passed since the last request, then records this one. The
+    /// time is kept in the cache so separate runs share the limit
+    pub fn throttle(&self) -> Result<(), FetchError> {
+        let path = self.cache_dir.join("last_request");
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        let last = std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|millis| millis.trim().parse().ok())
+            .map(Duration::from_millis)
+            .unwrap_or_default();
+
+        let wait = (last + self.min_interval).saturating_sub(now);
+        if !wait.is_zero() {
+            log::info!("Waiting {:.1?} before the next request", wait);
+            thread::sleep(wait);
+        }
+
+        std::fs::create_dir_all(&self.cache_dir)
+            .map_err(|source| io_error(&self.cache_dir, source))?;
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        std::fs::write(&path, now.as_millis().to_string()).map_err(|source| io_error(&path, source))
+    }
+}
+
+fn io_error(path: &Path, source: std::io::Error) -> FetchError {
+    FetchError::Io {
+        path: path.display().to_string(),
+        source,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server;
+    use std::time::Instant;
+
+    fn fetcher(base_url: &str, cache_dir: &Path) -> Fetcher {
+        Fetcher::new(Box::<UreqClient>::default(), base_url, cache_dir)
+            .with_session("abc")
+            .with_min_interval(Duration::ZERO)
+    }
+
+    #[test]
+    fn test_fetch_and_cache() {
+        let (base_url, requests) = test_server::serve(|request| match request.path.as_str() {
+            "/2023/day/7/input" => (200, "32T3K 765\n".to_string()),
+            _ => (404, "Not Found".to_string()),
+        });
+        let cache = tempfile::tempdir().unwrap();
+        let fetcher = fetcher(&base_url, cache.path());
+
+        assert_eq!(fetcher.fetch_input(7).unwrap(), "32T3K 765\n");
+        assert_eq!(fetcher.fetch_input(7).unwrap(), "32T3K 765\n");
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].cookie.as_deref(), Some("session=abc"));
+        assert_eq!(
+            std::fs::read_to_string(cache.path().join("2023/day_07.txt")).unwrap(),
+            "32T3K 765\n"
+        );
+    }
+
+    #[test]
+    fn test_fetch_errors() {
+        let (base_url, _) = test_server::serve(|request| match request.path.as_str() {
+            "/2023/day/1/input" => (
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input."
+                    .to_string(),
+            ),
+            "/2023/day/2/input" => (500, "Internal Server Error".to_string()),
+            _ => (404, "Not Found".to_string()),
+        });
+        let cache = tempfile::tempdir().unwrap();
+        let fetcher = fetcher(&base_url, cache.path());
+
+        assert!(matches!(
+            fetcher.fetch_input(1),
+            Err(FetchError::InvalidSession)
+        ));
+        assert!(matches!(
+            fetcher.fetch_input(2),
+            Err(FetchError::Status { status: 500, .. })
+        ));
+        assert!(matches!(
+            fetcher.fetch_input(25),
+            Err(FetchError::NotAvailable(25))
+        ));
+        assert!(!cache.path().join("2023/day_25.txt").exists());
+
+        let no_session = Fetcher::new(Box::<UreqClient>::default(), &base_url, cache.path());
+        assert!(matches!(
+            no_session.fetch_input(3),
+            Err(FetchError::NoSession(_))
+        ));
+    }
+
+    #[test]
+    fn test_throttle() {
+        let (base_url, _) = test_server::serve(|_| (200, "input".to_string()));
+        let cache = tempfile::tempdir().unwrap();
+        let fetcher =
+            fetcher(&base_url, cache.path()).with_min_interval(Duration::from_millis(300));
+
+        let start = Instant::now();
+        fetcher.fetch_input(1).unwrap();
+        fetcher.fetch_input(2).unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(300));
+    }
+}
@@ -0,0 +1,347 @@
+use crate::{
+    fetch::{FetchError, Fetcher},
+    YEAR,
+};
+use flate2::read::GzDecoder;
+use std::{
+    io::Read,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+use thiserror::Error;
+
+/// The environment variable pointing at a directory of inputs named like `day_07.txt`
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// The first two bytes of every gzip file
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+#[derive(Debug, Error)]
+pub enum InputError {
+    #[error("no input for day {day:02}, looked for {}", list(.searched))]
+    NotFound { day: u8, searched: Vec<PathBuf> },
+    #[error("no input for day {day:02} in {}, and couldn't download it: {source}", list(.searched))]
+    Fetch {
+        day: u8,
+        searched: Vec<PathBuf>,
+        source: FetchError,
+    },
+    #[error("couldn't read {origin}: {source}")]
+    Read {
+        origin: String,
+        source: std::io::Error,
+    },
+}
+
+fn list(paths: &[PathBuf]) -> String {
+    paths
+        .iter()
+        .map(|path| path.display().to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// A puzzle input, along with where it came from for error messages
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub text: String,
+    pub origin: String,
+}
+
+/// Where to read a single input from, given on the command line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Path(PathBuf),
+    Stdin,
+}
+
+impl From<&str> for Source {
+    fn from(arg: &str) -> Self {
+        match arg {
+            "-" => Source::Stdin,
+            path => Source::Path(PathBuf::from(path)),
+        }
+    }
+}
+
+/// Finds each day's input. A path (or `-` for stdin) given on the command line is the only place
+/// it looks, so a mistyped path is an error rather than quietly reading some other input.
+/// Otherwise it tries in order:
+///
+/// 1. `day_07.txt` in the directory named by `AOC_INPUT_DIR`
+/// 2. `input.txt` in the day's crate directory
+/// 3. `2023/day_07.txt` in the per-user cache directory
+/// 4. Downloading it into that cache, when there's a fetcher to do so
+///
+/// Any of the files can also be gzipped, either in place or alongside with a `.gz` extension. A path
+/// from the command line is read exactly as given, though it can still be gzipped in place.
+#[derive(Debug, Clone, Default)]
+pub struct InputProvider {
+    source: Option<Source>,
+    input_dir: Option<PathBuf>,
+    workspace_root: Option<PathBuf>,
+    cache_dir: Option<PathBuf>,
+    fetcher: Option<Arc<Fetcher>>,
+}
+
+impl InputProvider {
+    /// Looks in every location available to the runner, downloading the input as a last resort
+    pub fn from_env(source: Option<Source>) -> Self {
+        let cache_dir = default_cache_dir();
+        Self {
+            source,
+            input_dir: std::env::var_os(INPUT_DIR_VAR).map(PathBuf::from),
+            workspace_root: Some(crate::days::workspace_root()),
+            fetcher: cache_dir
+                .as_ref()
+                .map(|dir| Arc::new(Fetcher::from_env(dir))),
+            cache_dir,
+        }
+    }
+
+    pub fn with_source(mut self, source: Source) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    pub fn with_input_dir(mut self, input_dir: impl Into<PathBuf>) -> Self {
+        self.input_dir = Some(input_dir.into());
+        self
+    }
+
+    pub fn with_workspace_root(mut self, workspace_root: impl Into<PathBuf>) -> Self {
+        self.workspace_root = Some(workspace_root.into());
+        self
+    }
+
+    pub fn with_cache_dir(mut self, cache_dir: impl Into<PathBuf>) -> Self {
+        self.cache_dir = Some(cache_dir.into());
+        self
+    }
+
+    pub fn with_fetcher(mut self, fetcher: Fetcher) -> Self {
+        self.fetcher = Some(Arc::new(fetcher));
+        self
+    }
+
+    pub fn cache_dir(&self) -> Option<&Path> {
+        self.cache_dir.as_deref()
+    }
+
+    /// Every file that could hold the day's input, in the order they're tried
+    pub fn candidates(&self, day: u8) -> Vec<PathBuf> {
+        if let Some(Source::Path(path)) = &self.source {
+            return vec![path.clone()];
+        }
+        let file_name = format!("day_{:02}.txt", day);
+        let mut candidates = Vec::new();
+        if let Some(input_dir) = &self.input_dir {
+            candidates.push(input_dir.join(&file_name));
+        }
+        if let Some(root) = &self.workspace_root {
+            candidates.push(root.join(format!("day_{:02}", day)).join("input.txt"));
+        }
+        if let Some(cache_dir) = &self.cache_dir {
+            candidates.push(cache_dir.join(YEAR.to_string()).join(&file_name));
+        }
+        candidates
+    }
+
+    pub fn read(&self, day: u8) -> Result<Input, InputError> {
+        if self.source == Some(Source::Stdin) {
+            let mut bytes = Vec::new();
+            std::io::stdin()
+                .read_to_end(&mut bytes)
+                .map_err(|source| InputError::Read {
+                    origin: "stdin".to_string(),
+                    source,
+                })?;
+            return decode(bytes, "stdin".to_string());
+        }
+
+        let mut searched = Vec::new();
+        for candidate in self.candidates(day) {
+            let paths = match &self.source {
+                Some(Source::Path(_)) => vec![candidate],
+                _ => {
+                    let gzipped = PathBuf::from(format!("{}.gz", candidate.display()));
+                    vec![candidate, gzipped]
+                }
+            };
+            for path in paths {
+                if path.is_file() {
+                    let origin = path.display().to_string();
+                    let bytes = std::fs::read(&path).map_err(|source| InputError::Read {
+                        origin: origin.clone(),
+                        source,
+                    })?;
+                    return decode(bytes, origin);
+                }
+                searched.push(path);
+            }
+        }
+
+        // There's only something to download when no path was given
+        match (&self.source, &self.fetcher) {
+            (None, Some(fetcher)) => match fetcher.fetch_input(day) {
+                Ok(text) => Ok(Input {
+                    text,
+                    origin: fetcher.cache_path(day).display().to_string(),
+                }),
+                Err(source) => Err(InputError::Fetch {
+                    day,
+                    searched,
+                    source,
+                }),
+            },
+            _ => Err(InputError::NotFound { day, searched }),
+        }
+    }
+}
+
+/// `~/.cache/aoc` or the platform's equivalent
+pub fn default_cache_dir() -> Option<PathBuf> {
+    dirs::cache_dir().map(|dir| dir.join("aoc"))
+}
+
+/// Decompresses the bytes if they're gzipped, then checks they're text
+fn decode(bytes: Vec<u8>, origin: String) -> Result<Input, InputError> {
+    let bytes = match bytes.starts_with(&GZIP_MAGIC) {
+        true => {
+            let mut decompressed = Vec::new();
+            GzDecoder::new(bytes.as_slice())
+                .read_to_end(&mut decompressed)
+                .map_err(|source| InputError::Read {
+                    origin: origin.clone(),
+                    source,
+                })?;
+            decompressed
+        }
+        false => bytes,
+    };
+
+    match String::from_utf8(bytes) {
+        Ok(text) => Ok(Input { text, origin }),
+        Err(e) => Err(InputError::Read {
+            origin,
+            source: std::io::Error::new(std::io::ErrorKind::InvalidData, e),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fetch::UreqClient;
+    use flate2::{write::GzEncoder, Compression};
+    use std::{io::Write, time::Duration};
+
+    fn gzip(text: &str) -> Vec<u8> {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(text.as_bytes()).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    #[test]
+    fn test_search_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let (input_dir, root, cache) = (
+            dir.path().join("in"),
+            dir.path().join("ws"),
+            dir.path().join("cache"),
+        );
+        std::fs::create_dir_all(&input_dir).unwrap();
+        std::fs::create_dir_all(root.join("day_07")).unwrap();
+        std::fs::create_dir_all(cache.join("2023")).unwrap();
+        let provider = InputProvider::default()
+            .with_input_dir(&input_dir)
+            .with_workspace_root(&root)
+            .with_cache_dir(&cache);
+
+        std::fs::write(cache.join("2023/day_07.txt"), "cache").unwrap();
+        assert_eq!(provider.read(7).unwrap().text, "cache");
+        std::fs::write(root.join("day_07/input.txt.gz"), gzip("crate")).unwrap();
+        assert_eq!(provider.read(7).unwrap().text, "crate");
+        std::fs::write(input_dir.join("day_07.txt"), "dir").unwrap();
+        assert_eq!(provider.read(7).unwrap().text, "dir");
+
+        let explicit = dir.path().join("explicit.txt");
+        std::fs::write(&explicit, gzip("explicit")).unwrap();
+        let input = provider
+            .with_source(Source::Path(explicit.clone()))
+            .read(7)
+            .unwrap();
+        assert_eq!(input.text, "explicit");
+        assert_eq!(input.origin, explicit.display().to_string());
+    }
+
+    #[test]
+    fn test_explicit_path_only() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("2023")).unwrap();
+        std::fs::write(dir.path().join("2023/day_05.txt"), "cache").unwrap();
+        let fetcher = Fetcher::new(Box::<UreqClient>::default(), "http://unused", dir.path());
+        let missing = dir.path().join("missing.txt");
+        std::fs::write(dir.path().join("missing.txt.gz"), gzip("alongside")).unwrap();
+        let provider = InputProvider::default()
+            .with_input_dir(dir.path())
+            .with_workspace_root(dir.path())
+            .with_cache_dir(dir.path())
+            .with_fetcher(fetcher)
+            .with_source(Source::Path(missing.clone()));
+
+        assert_eq!(provider.candidates(5), vec![missing.clone()]);
+        match provider.read(5) {
+            Err(InputError::NotFound { day, searched }) => {
+                assert_eq!(day, 5);
+                assert_eq!(searched, vec![missing]);
+            }
+            other => panic!("expected NotFound, got {:?}", other),
+        }
+
+        // Nor does a path that isn't a file send it looking anywhere else
+        let provider = provider.with_source(Source::Path(dir.path().join("2023")));
+        assert!(matches!(provider.read(5), Err(InputError::NotFound { .. })));
+
+        // Though the path itself can be gzipped
+        let gzipped = dir.path().join("missing.txt.gz");
+        let input = provider.with_source(Source::Path(gzipped)).read(5).unwrap();
+        assert_eq!(input.text, "alongside");
+    }
+
+    #[test]
+    fn test_download_fallback() {
+        let (base_url, requests) = crate::test_server::serve(|_| (200, "downloaded".to_string()));
+        let cache = tempfile::tempdir().unwrap();
+        let fetcher = Fetcher::new(Box::<UreqClient>::default(), &base_url, cache.path())
+            .with_session("abc")
+            .with_min_interval(Duration::ZERO);
+        let provider = InputProvider::default()
+            .with_cache_dir(cache.path())
+            .with_fetcher(fetcher);
+
+        assert_eq!(provider.read(4).unwrap().text, "downloaded");
+        // Now it's in the cache, so there's no need to download it again
+        assert_eq!(provider.read(4).unwrap().text, "downloaded");
+        assert_eq!(requests.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_not_found() {
+        let dir = tempfile::tempdir().unwrap();
+        let provider = InputProvider::default().with_cache_dir(dir.path());
+        match provider.read(3) {
+            Err(InputError::NotFound { day, searched }) => {
+                assert_eq!(day, 3);
+                assert_eq!(
+                    searched,
+                    vec![
+                        dir.path().join("2023/day_03.txt"),
+                        dir.path().join("2023/day_03.txt.gz"),
+                    ]
+                );
+            }
+            other => panic!("expected NotFound, got {:?}", other),
+        }
+    }
+}
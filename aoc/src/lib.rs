@@ -0,0 +1,21 @@
+//! The pieces of the runner, shared between the `aoc` binary and the benchmarks
+
+pub mod answers;
+pub mod days;
+pub mod extract;
+pub mod fetch;
+pub mod input;
+pub mod memory;
+pub mod readme;
+pub mod report;
+pub mod scaffold;
+pub mod selection;
+pub mod submit;
+pub mod verify;
+pub mod watch;
+
+#[cfg(test)]
+mod test_server;
+
+/// The year of the puzzles, for the site's urls and the input cache
+pub const YEAR: u16 = 2023;
@@ -0,0 +1,743 @@
+use aoc::{
+    answers::Answers,
+    days::{self, Day, DAYS},
+    extract,
+    fetch::Fetcher,
+    input::{self, Input, InputProvider, Source},
+    memory::PeakAllocator,
+    readme,
+    report::{self, PartReport, RunStatus},
+    scaffold,
+    selection::DaySelection,
+    submit::{self, History, Verdict},
+    verify::{self, PartCheck, Status, Summary},
+    watch::{self, Change, DayWatcher, WatchedDay},
+};
+use aoc_core::Part;
+use clap::{Parser, Subcommand, ValueEnum};
+use log::LevelFilter;
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+/// Counts the memory in use, so each part's peak can be reported
+#[global_allocator]
+static ALLOCATOR: PeakAllocator = PeakAllocator;
+
+#[derive(Parser)]
+#[command(author, version, about = "Runs the Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Log more of what the solutions are doing, repeat for more detail (`-vvv` for everything).
+    /// `RUST_LOG` (e.g. `RUST_LOG=day_07=trace`) is applied on top
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solutions of the selected days against their inputs
+    Run {
+        /// `all`, a single day such as `7`, or an inclusive range such as `3-7`
+        days: DaySelection,
+        /// Only run the given part
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Read the input of a single day from this file, or `-` for stdin. Otherwise it's looked
+        /// for in `$AOC_INPUT_DIR/day_07.txt`, `day_07/input.txt`, then the user's cache directory
+        #[arg(short, long)]
+        input: Option<String>,
+        /// Run against the examples from the puzzle descriptions instead, checking their answers
+        #[arg(short, long, conflicts_with = "input")]
+        example: bool,
+        /// Print the answers as text, or as JSON along with their timings and memory use
+        #[arg(short, long, value_enum, default_value_t = Format::Text, conflicts_with = "example")]
+        format: Format,
+        /// Give up on parsing or solving a part after this many seconds, or 0 to wait for it
+        #[arg(short, long, default_value_t = 60.0, conflicts_with = "example")]
+        timeout: f64,
+        /// How many days to run at once. Defaults to the number of cores
+        #[arg(short, long, conflicts_with = "example")]
+        jobs: Option<usize>,
+    },
+    /// Check the selected days still give the answers recorded in answers.toml
+    Verify {
+        /// `all`, a single day such as `7`, or an inclusive range such as `3-7`
+        #[arg(default_value = "all")]
+        days: DaySelection,
+    },
+    /// Download the inputs of the selected days into the cache, unless they're already there.
+    /// Needs the session cookie of a logged in browser in `AOC_SESSION`
+    Fetch {
+        /// `all`, a single day such as `7`, or an inclusive range such as `3-7`
+        days: DaySelection,
+    },
+    /// Solve a part and send the answer, unless earlier submissions show it's wrong or too soon
+    Submit {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+    },
+    /// Make up inputs that look like the real ones, printing a single day's or writing them into a
+    /// directory to run against with `AOC_INPUT_DIR`
+    Generate {
+        /// `all`, a single day such as `7`, or an inclusive range such as `3-7`
+        days: DaySelection,
+        /// The same seed always makes up the same input
+        #[arg(short, long, default_value_t = 0)]
+        seed: u64,
+        /// How big to make each input, in whatever the day counts. Defaults to as big as the real ones
+        #[arg(long)]
+        size: Option<usize>,
+        /// Write each day's input into this directory as `day_07.txt`
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Verify every solved day and rewrite the README's progress table from the results
+    Readme,
+    /// Write the examples and answers from a saved puzzle page into the day's `examples` directory
+    Extract {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// The puzzle page, as saved from the browser
+        page: PathBuf,
+        /// Replace examples that already have answers
+        #[arg(short, long)]
+        force: bool,
+    },
+    /// Rebuild the day and re-run its tests and both parts whenever its sources, examples or input
+    /// change, showing how the answers changed
+    Watch {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Create the crate for a new day and add it to the workspace, runner, benchmarks and answers
+    New {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    init_logging(cli.verbose);
+    match cli.command {
+        Command::Run {
+            days,
+            part,
+            input,
+            example: false,
+            format,
+            timeout,
+            jobs,
+        } => {
+            let timeout = (timeout > 0.0).then(|| Duration::from_secs_f64(timeout));
+            let jobs = jobs.unwrap_or_else(|| {
+                std::thread::available_parallelism().map_or(1, |cores| cores.get())
+            });
+            run(&days, part, input.as_deref(), format, timeout, jobs)
+        }
+        Command::Run { days, part, .. } => run_examples(&days, part),
+        Command::Verify { days } => verify(&days),
+        Command::Fetch { days } => fetch(&days),
+        Command::Submit { day, part } => submit_answer(day, part),
+        Command::Generate {
+            days,
+            seed,
+            size,
+            output,
+        } => generate(&days, seed, size, output.as_deref()),
+        Command::Readme => update_readme(),
+        Command::Extract { day, page, force } => extract_examples(day, &page, force),
+        Command::Watch { day } => watch_day(day),
+        Command::New { day } => new_day(day),
+    }
+}
+
+/// Quiet apart from warnings by default, with each `-v` turning on the next level
+fn init_logging(verbose: u8) {
+    let level = match verbose {
+        0 => LevelFilter::Warn,
+        1 => LevelFilter::Info,
+        2 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
+    };
+    env_logger::Builder::new()
+        .filter_level(level)
+        .parse_default_env()
+        .init();
+}
+
+/// The solved days in the selection, or `None` after saying there aren't any
+fn select(selection: &DaySelection) -> Option<Vec<&'static Day>> {
+    let days = DAYS
+        .iter()
+        .filter(|day| selection.contains(day.number))
+        .collect::<Vec<&Day>>();
+    if days.is_empty() {
+        eprintln!("No solved days in {}", selection);
+        return None;
+    }
+    Some(days)
+}
+
+fn read_input(provider: &InputProvider, day: &Day) -> Option<Input> {
+    match provider.read(day.number) {
+        Ok(input) => {
+            log::info!("Reading day {:02} from {}", day.number, input.origin);
+            Some(input)
+        }
+        Err(e) => {
+            eprintln!("  {}", e);
+            None
+        }
+    }
+}
+
+fn run(
+    selection: &DaySelection,
+    part: Option<u8>,
+    input: Option<&str>,
+    format: Format,
+    timeout: Option<Duration>,
+    jobs: usize,
+) -> ExitCode {
+    let Some(days) = select(selection) else {
+        return ExitCode::FAILURE;
+    };
+    if input.is_some() && days.len() > 1 {
+        eprintln!(
+            "--input can only be given for a single day, not {}",
+            selection
+        );
+        return ExitCode::FAILURE;
+    }
+    let provider = InputProvider::from_env(input.map(Source::from));
+    let parts = match part.and_then(Part::from_number) {
+        Some(part) => vec![part],
+        None => Part::BOTH.to_vec(),
+    };
+
+    // The inputs are read up front, as downloading them has to be spaced out anyway
+    let start = Instant::now();
+    let inputs = days
+        .into_iter()
+        .map(|day| {
+            let input = provider.read(day.number).map_err(|e| e.to_string());
+            if let Ok(input) = &input {
+                log::info!("Reading day {:02} from {}", day.number, input.origin);
+            }
+            (day, input)
+        })
+        .collect();
+    let run = report::run_days(inputs, &parts, timeout, jobs);
+    let wall_time = start.elapsed();
+    let reports = run.reports;
+
+    match format {
+        Format::Text => print_table(&reports, run.still_running, wall_time),
+        Format::Json => match serde_json::to_string_pretty(&reports) {
+            Ok(json) => {
+                println!("{}", json);
+                if run.still_running > 0 {
+                    log::warn!(
+                        "{} timed out step(s) were still running when the runner exited",
+                        run.still_running
+                    );
+                }
+            }
+            Err(e) => {
+                eprintln!("Couldn't write the results as JSON: {}", e);
+                return ExitCode::FAILURE;
+            }
+        },
+    }
+    match reports
+        .iter()
+        .all(|report| report.status == RunStatus::Solved)
+    {
+        true => ExitCode::SUCCESS,
+        false => ExitCode::FAILURE,
+    }
+}
+
+/// Prints a row for every part, followed by why any of them went unsolved and how many that timed
+/// out hadn't finished yet
+fn print_table(reports: &[PartReport], still_running: usize, wall_time: Duration) {
+    let time = |ns: Option<u64>| match ns {
+        Some(ns) => format!("{:.2?}", Duration::from_nanos(ns)),
+        None => "-".to_string(),
+    };
+    let memory = |bytes: Option<isize>| match bytes {
+        Some(bytes) if bytes >= 1 << 20 => format!("{:.1} MiB", bytes as f64 / (1 << 20) as f64),
+        Some(bytes) => format!("{:.1} KiB", bytes as f64 / 1024.0),
+        None => "-".to_string(),
+    };
+    let status = |status: RunStatus| match status {
+        RunStatus::Solved => "solved",
+        RunStatus::NoInput => "no input",
+        RunStatus::ParseError => "parse error",
+        RunStatus::TimedOut => "timed out",
+        RunStatus::Panicked => "panicked",
+    };
+
+    println!(
+        "{:>3} {:>4}  {:>16}  {:>10}  {:>10}  {:>10}  Status",
+        "Day", "Part", "Answer", "Parse", "Solve", "Memory"
+    );
+    for report in reports {
+        println!(
+            "{:>3} {:>4}  {:>16}  {:>10}  {:>10}  {:>10}  {}",
+            format!("{:02}", report.day),
+            report.part,
+            report.answer.as_deref().unwrap_or("-"),
+            time(report.parse_time_ns),
+            time(report.solve_time_ns),
+            memory(report.peak_memory_bytes),
+            status(report.status)
+        );
+    }
+    println!("Total wall time: {:.2?}", wall_time);
+    if still_running > 0 {
+        println!(
+            "Still running after timing out: {} (stopped when the runner exits)",
+            still_running
+        );
+    }
+
+    let mut errors: Vec<String> = reports
+        .iter()
+        .filter_map(|report| {
+            let error = report.error.as_ref()?;
+            Some(format!("Day {:02}: {}", report.day, error))
+        })
+        .collect();
+    // Parsing errors are the same for every part of the day
+    errors.dedup();
+    for error in errors {
+        eprintln!("{}", error);
+    }
+}
+
+fn run_examples(selection: &DaySelection, part: Option<u8>) -> ExitCode {
+    let Some(days) = select(selection) else {
+        return ExitCode::FAILURE;
+    };
+
+    let wanted = |check: Part| part.is_none() || part == Some(check.number());
+    let mut exit_code = ExitCode::SUCCESS;
+    for day in days {
+        let examples = day.examples.all();
+        let examples = examples
+            .iter()
+            .filter(|example| example.parts().into_iter().any(wanted))
+            .collect::<Vec<_>>();
+        if examples.is_empty() {
+            eprintln!("Day {:02} has no examples with answers", day.number);
+            exit_code = ExitCode::FAILURE;
+        }
+        for example in examples {
+            let check = match verify::check_example(day, example) {
+                Ok(check) => check,
+                Err(e) => {
+                    eprintln!("Day {:02} {}: {}", day.number, example.name, e);
+                    exit_code = ExitCode::FAILURE;
+                    continue;
+                }
+            };
+
+            println!("Day {:02} {}", day.number, example.name);
+            for part in check.parts.iter().filter(|check| wanted(check.part)) {
+                print_part(part);
+                if !matches!(part.status, Status::Pass) {
+                    exit_code = ExitCode::FAILURE;
+                }
+            }
+        }
+    }
+    exit_code
+}
+
+fn print_part(part: &PartCheck) {
+    let detail = match &part.status {
+        Status::Fail { expected } => format!("{}, expected {}", part.answer, expected),
+        _ => part.answer.clone(),
+    };
+    println!(
+        "  Part {}: {:<7} {} in {:.2?}",
+        part.part, part.status, detail, part.time
+    );
+}
+
+fn verify(selection: &DaySelection) -> ExitCode {
+    let Some(days) = select(selection) else {
+        return ExitCode::FAILURE;
+    };
+    let answers = match Answers::load(&days::answers_path()) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("Couldn't load the answers: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let provider = InputProvider::from_env(None);
+    let mut summary = Summary::default();
+    for day in days {
+        let Some(input) = read_input(&provider, day) else {
+            summary.errors += 1;
+            continue;
+        };
+        let check = match verify::check(day, &input.text, &answers) {
+            Ok(check) => check,
+            Err(e) => {
+                eprintln!("  Couldn't parse {}: {}", input.origin, e);
+                summary.errors += 1;
+                continue;
+            }
+        };
+
+        println!(
+            "Day {:02} (parsed in {:.2?})",
+            check.number, check.parse_time
+        );
+        for part in check.parts.iter() {
+            print_part(part);
+        }
+        summary.add(&check);
+    }
+
+    println!("{}", summary);
+    summary.exit_code()
+}
+
+fn fetch(selection: &DaySelection) -> ExitCode {
+    let Some(cache_dir) = input::default_cache_dir() else {
+        eprintln!("Couldn't find a cache directory to download into");
+        return ExitCode::FAILURE;
+    };
+    let fetcher = Fetcher::from_env(cache_dir);
+
+    let mut exit_code = ExitCode::SUCCESS;
+    for day in (1..=25).filter(|day| selection.contains(*day)) {
+        match fetcher.fetch_input(day) {
+            Ok(_) => println!("Day {:02}: {}", day, fetcher.cache_path(day).display()),
+            Err(e) => {
+                eprintln!("Day {:02}: {}", day, e);
+                exit_code = ExitCode::FAILURE;
+            }
+        }
+    }
+    exit_code
+}
+
+fn submit_answer(number: u8, part: u8) -> ExitCode {
+    let Some(day) = DAYS.iter().find(|day| day.number == number) else {
+        eprintln!("Day {:02} isn't solved yet", number);
+        return ExitCode::FAILURE;
+    };
+    let Some(part) = Part::from_number(part) else {
+        return ExitCode::FAILURE;
+    };
+    let Some(cache_dir) = input::default_cache_dir() else {
+        eprintln!("Couldn't find a cache directory for the submission history");
+        return ExitCode::FAILURE;
+    };
+
+    println!("Day {:02}", day.number);
+    let Some(input) = read_input(&InputProvider::from_env(None), day) else {
+        return ExitCode::FAILURE;
+    };
+    let answer = match (day.parse)(&input.text) {
+        Ok(solution) => solution.answer(part),
+        Err(e) => {
+            eprintln!("  Couldn't parse {}: {}", input.origin, e);
+            return ExitCode::FAILURE;
+        }
+    };
+    println!("  Part {}: {}", part, answer);
+
+    let history_path = History::path(&cache_dir);
+    let fetcher = Fetcher::from_env(cache_dir);
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let result = History::load(&history_path).and_then(|mut history| {
+        let verdict = submit::submit(&fetcher, &mut history, day.number, part, &answer, now)?;
+        history.save(&history_path)?;
+        Ok(verdict)
+    });
+    match result {
+        Ok(verdict) => {
+            println!("  {}", verdict);
+            match verdict {
+                Verdict::Correct => {
+                    println!("  Record it in answers.toml so `aoc verify` keeps checking it");
+                    ExitCode::SUCCESS
+                }
+                Verdict::AlreadySolved => ExitCode::SUCCESS,
+                _ => ExitCode::FAILURE,
+            }
+        }
+        Err(e) => {
+            eprintln!("  {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn generate(
+    selection: &DaySelection,
+    seed: u64,
+    size: Option<usize>,
+    output: Option<&Path>,
+) -> ExitCode {
+    let Some(days) = select(selection) else {
+        return ExitCode::FAILURE;
+    };
+    let Some(output) = output else {
+        let [day] = days[..] else {
+            eprintln!("Pass --output to generate more than one day");
+            return ExitCode::FAILURE;
+        };
+        let size = size.unwrap_or(day.generator.size);
+        print!("{}", (day.generator.generate)(seed, size));
+        return ExitCode::SUCCESS;
+    };
+
+    if let Err(e) = std::fs::create_dir_all(output) {
+        eprintln!("Couldn't create {}: {}", output.display(), e);
+        return ExitCode::FAILURE;
+    }
+    let mut exit_code = ExitCode::SUCCESS;
+    for day in days {
+        let size = size.unwrap_or(day.generator.size);
+        let path = output.join(format!("day_{:02}.txt", day.number));
+        match std::fs::write(&path, (day.generator.generate)(seed, size)) {
+            Ok(()) => println!("Day {:02}: {}", day.number, path.display()),
+            Err(e) => {
+                eprintln!(
+                    "Day {:02}: couldn't write {}: {}",
+                    day.number,
+                    path.display(),
+                    e
+                );
+                exit_code = ExitCode::FAILURE;
+            }
+        }
+    }
+    exit_code
+}
+
+fn update_readme() -> ExitCode {
+    let answers = match Answers::load(&days::answers_path()) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("Couldn't load the answers: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let provider = InputProvider::from_env(None);
+    let mut checks = BTreeMap::new();
+    for day in DAYS {
+        println!("Day {:02}", day.number);
+        let Some(input) = read_input(&provider, day) else {
+            continue;
+        };
+        match verify::check(day, &input.text, &answers) {
+            Ok(check) => _ = checks.insert(day.number, check),
+            Err(e) => eprintln!("  Couldn't parse {}: {}", input.origin, e),
+        }
+    }
+
+    let root = days::workspace_root();
+    let readme_path = root.join("README.md");
+    let table = readme::render_table(&checks, |day| root.join(format!("day_{:02}", day)).is_dir());
+    let updated = std::fs::read_to_string(&readme_path)
+        .map_err(|e| e.to_string())
+        .and_then(|readme| {
+            readme::replace_table(&readme, &table).ok_or_else(|| {
+                format!(
+                    "couldn't find the {} and {} markers",
+                    readme::TABLE_START,
+                    readme::TABLE_END
+                )
+            })
+        })
+        .and_then(|readme| std::fs::write(&readme_path, readme).map_err(|e| e.to_string()));
+    match updated {
+        Ok(()) => {
+            let stars = checks
+                .values()
+                .flat_map(|check| check.parts.iter())
+                .filter(|part| part.status == verify::Status::Pass)
+                .count();
+            println!("Updated {} with {} stars", readme_path.display(), stars);
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("Couldn't update {}: {}", readme_path.display(), e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn extract_examples(number: u8, page: &Path, force: bool) -> ExitCode {
+    let crate_dir = days::workspace_root().join(format!("day_{:02}", number));
+    if !crate_dir.is_dir() {
+        eprintln!(
+            "There's no day_{:02} yet, create it with `aoc new {}`",
+            number, number
+        );
+        return ExitCode::FAILURE;
+    }
+    let page = match std::fs::read_to_string(page) {
+        Ok(page) => page,
+        Err(e) => {
+            eprintln!("Couldn't read {}: {}", page.display(), e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let examples = extract::extract(&page);
+    match extract::write(&crate_dir, &examples, force) {
+        Ok(touched) => {
+            for example in examples.iter() {
+                let answer = |answer: &Option<String>| answer.clone().unwrap_or("-".to_string());
+                println!(
+                    "{}: part 1 {}, part 2 {}",
+                    example.name,
+                    answer(&example.part_one),
+                    answer(&example.part_two)
+                );
+            }
+            for path in touched {
+                println!("  {}", path.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("Couldn't extract the examples of day {:02}: {}", number, e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn new_day(number: u8) -> ExitCode {
+    match scaffold::scaffold(&days::workspace_root(), number) {
+        Ok(touched) => {
+            println!("Created day_{:02}", number);
+            for path in touched {
+                println!("  {}", path.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("Couldn't create day_{:02}: {}", number, e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn watch_day(number: u8) -> ExitCode {
+    let root = days::workspace_root();
+    let provider = InputProvider::from_env(None);
+    let day = WatchedDay::new(&root, number, provider.candidates(number));
+    if !day.crate_dir.is_dir() {
+        eprintln!(
+            "There's no day_{:02} yet, create it with `aoc new {}`",
+            number, number
+        );
+        return ExitCode::FAILURE;
+    }
+    let watcher = match DayWatcher::start(day.clone()) {
+        Ok(watcher) => watcher,
+        Err(e) => {
+            eprintln!("Couldn't watch day {:02}: {}", number, e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let solved = DAYS.iter().any(|day| day.number == number);
+    if !solved {
+        println!(
+            "Day {:02} isn't in the runner yet, so only its tests are run",
+            number
+        );
+    }
+
+    let mut previous: Option<Vec<PartReport>> = None;
+    let mut changed = Vec::new();
+    loop {
+        match changed.is_empty() {
+            true => println!("Day {:02}", number),
+            false => {
+                let changed: Vec<String> = changed
+                    .iter()
+                    .map(|path: &PathBuf| {
+                        let path = path.strip_prefix(&root).unwrap_or(path);
+                        path.display().to_string()
+                    })
+                    .collect();
+                println!("Day {:02}, after {} changed", number, changed.join(", "));
+            }
+        }
+
+        match day.test() {
+            Ok(true) => println!("  Tests passed"),
+            Ok(false) => println!("  Tests failed"),
+            Err(e) => eprintln!("  {}", e),
+        }
+        if solved {
+            match day.solve() {
+                Ok(reports) => {
+                    for report in reports.iter() {
+                        print_change(report, watch::compare(previous.as_deref(), report));
+                    }
+                    previous = Some(reports);
+                }
+                Err(e) => eprintln!("  {}", e),
+            }
+        }
+
+        println!("Waiting for changes...");
+        changed = match watcher.next_change() {
+            Ok(changed) => changed,
+            Err(e) => {
+                eprintln!("Stopped watching day {:02}: {}", number, e);
+                return ExitCode::FAILURE;
+            }
+        };
+    }
+}
+
+fn print_change(report: &PartReport, change: Change) {
+    let answer = match (&report.answer, &report.error) {
+        (Some(answer), _) => answer.clone(),
+        (None, Some(error)) => error.clone(),
+        (None, None) => "-".to_string(),
+    };
+    let change = match change {
+        Change::First => String::new(),
+        Change::Same => " (unchanged)".to_string(),
+        Change::Changed { was: Some(was) } => format!(" (was {})", was),
+        Change::Changed { was: None } => " (was unsolved)".to_string(),
+    };
+    let time = report
+        .solve_time_ns
+        .map(|ns| format!(" in {:.2?}", Duration::from_nanos(ns)))
+        .unwrap_or_default();
+    println!("  Part {}: {}{}{}", report.part, answer, change, time);
+}
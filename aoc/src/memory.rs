@@ -0,0 +1,103 @@
+//! Keeps count of the heap memory each thread has in use, and the most it's had in use at once, so
+//! the runner can report how much each part needed even while other days run alongside it. The
+//! counts only move once the binary makes [`PeakAllocator`] its `#[global_allocator]`.
+//!
+//! Memory is counted against the thread that frees it as well as the one that allocated it, so a
+//! thread that frees memory from elsewhere can end up with less than nothing in use.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+thread_local! {
+    // Neither of these allocate or need dropping, so they're safe to use from the allocator
+    static CURRENT: Cell<isize> = const { Cell::new(0) };
+    static PEAK: Cell<isize> = const { Cell::new(0) };
+}
+
+/// The system allocator, counting every allocation as it goes
+pub struct PeakAllocator;
+
+unsafe impl GlobalAlloc for PeakAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            add(layout.size() as isize);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            add(layout.size() as isize);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        add(-(layout.size() as isize));
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            add(new_size as isize - layout.size() as isize);
+        }
+        new_ptr
+    }
+}
+
+fn add(size: isize) {
+    // Threads that are shutting down can't be counted any more, which is fine as they're done
+    let _ = CURRENT.try_with(|current| {
+        current.set(current.get() + size);
+        let _ = PEAK.try_with(|peak| peak.set(peak.get().max(current.get())));
+    });
+}
+
+/// The bytes of heap memory the current thread has in use right now
+pub fn current() -> isize {
+    CURRENT.with(Cell::get)
+}
+
+/// The most bytes of heap memory the current thread has had in use at once since the last
+/// [`reset_peak`], or since it started
+pub fn peak() -> isize {
+    PEAK.with(Cell::get)
+}
+
+/// Starts counting the current thread's peak again from what it has in use right now
+pub fn reset_peak() {
+    PEAK.with(|peak| peak.set(current()));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_peak() {
+        // Only this test goes through the allocator, since it isn't the global one in tests
+        let layout = Layout::from_size_align(1024, 8).unwrap();
+        reset_peak();
+        let before = current();
+        unsafe {
+            let ptr = PeakAllocator.alloc(layout);
+            assert_eq!(current(), before + 1024);
+            let ptr = PeakAllocator.realloc(ptr, layout, 4096);
+            assert_eq!(current(), before + 4096);
+            PeakAllocator.dealloc(ptr, Layout::from_size_align(4096, 8).unwrap());
+        }
+        assert_eq!(current(), before);
+        assert_eq!(peak(), before + 4096);
+
+        reset_peak();
+        assert_eq!(peak(), before);
+
+        // Other threads keep their own counts
+        std::thread::spawn(|| assert_eq!(peak(), 0)).join().unwrap();
+    }
+}
@@ -0,0 +1,92 @@
+use crate::verify::{DayCheck, Status};
+use std::collections::BTreeMap;
+
+/// Marks where the generated progress table starts in the README
+pub const TABLE_START: &str = "<!-- progress table start -->";
+/// Marks where the generated progress table ends in the README
+pub const TABLE_END: &str = "<!-- progress table end -->";
+
+/// Renders the progress table for all 25 days, with a star and runtime for each passing part
+///
+/// Days with a crate but no checks (not solved yet) still link to their crate.
+pub fn render_table(checks: &BTreeMap<u8, DayCheck>, has_crate: impl Fn(u8) -> bool) -> String {
+    let mut table = String::from(
+        "Day | Star 1 | Star 2 | Part 1 | Part 2\n:-- | :----: | :----: | -----: | -----:\n",
+    );
+    for number in 1..=25 {
+        let day = match has_crate(number) {
+            true => format!("[{:02}](day_{:02})", number, number),
+            false => format!("{:02}", number),
+        };
+        let mut stars = vec![String::new(); 2];
+        let mut times = vec![String::new(); 2];
+        if let Some(check) = checks.get(&number) {
+            for (i, part) in check.parts.iter().enumerate() {
+                if part.status == Status::Pass {
+                    stars[i] = ":star:".to_string();
+                    times[i] = format!("{:.2?}", part.time);
+                }
+            }
+        }
+        table += &format!(
+            "{} | {} | {} | {} | {}\n",
+            day, stars[0], stars[1], times[0], times[1]
+        );
+    }
+    table
+}
+
+/// Swaps the table between the markers for the given one, or `None` if the markers are missing
+pub fn replace_table(readme: &str, table: &str) -> Option<String> {
+    let start = readme.find(TABLE_START)? + TABLE_START.len();
+    let end = start + readme[start..].find(TABLE_END)?;
+    Some(format!("{}\n{}{}", &readme[..start], table, &readme[end..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::verify::PartCheck;
+    use aoc_core::Part;
+    use std::time::Duration;
+
+    #[test]
+    fn test_render_table() {
+        let check = DayCheck {
+            number: 2,
+            parse_time: Duration::from_micros(5),
+            parts: vec![
+                PartCheck {
+                    part: Part::One,
+                    answer: "8".to_string(),
+                    time: Duration::from_micros(12),
+                    status: Status::Pass,
+                },
+                PartCheck {
+                    part: Part::Two,
+                    answer: "2286".to_string(),
+                    time: Duration::from_micros(30),
+                    status: Status::Missing,
+                },
+            ],
+        };
+        let checks = BTreeMap::from([(2, check)]);
+        let table = render_table(&checks, |day| day <= 3);
+        let lines = table.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines.len(), 27);
+        assert_eq!(lines[2], "[01](day_01) |  |  |  | ");
+        assert_eq!(lines[3], "[02](day_02) | :star: |  | 12.00µs | ");
+        assert_eq!(lines[5], "04 |  |  |  | ");
+    }
+
+    #[test]
+    fn test_replace_table() {
+        let readme = format!("# Title\n{}\nold\n{}\nAfter", TABLE_START, TABLE_END);
+        assert_eq!(
+            replace_table(&readme, "new\n").unwrap(),
+            format!("# Title\n{}\nnew\n{}\nAfter", TABLE_START, TABLE_END)
+        );
+        assert_eq!(replace_table("# Title", "new\n"), None);
+    }
+}
@@ -0,0 +1,356 @@
+//! Runs the days side by side and keeps what `aoc run` found for each part of each day, for
+//! printing or for `--format json`
+
+use crate::{days::Day, input::Input, memory};
+use aoc_core::{DynSolution, Part};
+use serde::{Deserialize, Serialize};
+use std::{
+    sync::{mpsc, Arc},
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+use threadpool::ThreadPool;
+
+/// How running a part went
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RunStatus {
+    Solved,
+    /// There was no input to be found for the day
+    NoInput,
+    /// The day couldn't parse its input
+    ParseError,
+    /// Parsing or solving took longer than the timeout. It isn't cancelled, only given up on, so it
+    /// carries on in the background until it finishes or the runner exits
+    TimedOut,
+    /// Parsing or solving panicked
+    Panicked,
+}
+
+/// A single part of a day, as it's written out by `aoc run --format json`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartReport {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    /// How long the day took to parse its input, shared between its parts
+    pub parse_time_ns: Option<u64>,
+    pub solve_time_ns: Option<u64>,
+    /// The most heap memory in use at once while parsing the input or solving the part
+    pub peak_memory_bytes: Option<isize>,
+    pub status: RunStatus,
+    /// Why the part couldn't be solved
+    pub error: Option<String>,
+}
+
+impl PartReport {
+    /// A part that couldn't be solved for the given reason
+    pub fn failed(day: u8, part: Part, status: RunStatus, error: String) -> Self {
+        Self {
+            day,
+            part: part.number(),
+            answer: None,
+            parse_time_ns: None,
+            solve_time_ns: None,
+            peak_memory_bytes: None,
+            status,
+            error: Some(error),
+        }
+    }
+}
+
+/// What came of running the days
+#[derive(Debug)]
+pub struct Run {
+    /// In order of day and part, whatever order they finished in
+    pub reports: Vec<PartReport>,
+    /// How many of the parses and parts that timed out were still running once the rest were done.
+    /// They're on threads of their own on top of the `workers`, and don't stop until they finish
+    /// or the runner exits.
+    pub still_running: usize,
+}
+
+/// Runs each day on a pool of `workers` threads, giving up on any parse or part that takes longer
+/// than the timeout. Giving up doesn't stop it, so every one that times out keeps a thread busy
+/// alongside the pool's, which is counted in [`Run::still_running`].
+pub fn run_days(
+    days: Vec<(&'static Day, Result<Input, String>)>,
+    parts: &[Part],
+    timeout: Option<Duration>,
+    workers: usize,
+) -> Run {
+    let pool = ThreadPool::new(workers.max(1));
+    let (sender, receiver) = mpsc::channel();
+    for (day, input) in days {
+        let sender = sender.clone();
+        let parts = parts.to_vec();
+        pool.execute(move || {
+            let mut background = Vec::new();
+            let reports = match input {
+                Ok(input) => run_day(
+                    day,
+                    input.text,
+                    &input.origin,
+                    &parts,
+                    timeout,
+                    &mut background,
+                ),
+                Err(error) => parts
+                    .iter()
+                    .map(|&part| {
+                        PartReport::failed(day.number, part, RunStatus::NoInput, error.clone())
+                    })
+                    .collect(),
+            };
+            let _ = sender.send((reports, background));
+        });
+    }
+    drop(sender);
+
+    let mut reports = Vec::new();
+    let mut background = Vec::new();
+    for (day_reports, day_background) in receiver {
+        reports.extend(day_reports);
+        background.extend(day_background);
+    }
+    reports.sort_by_key(|report| (report.day, report.part));
+    let still_running = background
+        .iter()
+        .filter(|handle| !handle.is_finished())
+        .count();
+    Run {
+        reports,
+        still_running,
+    }
+}
+
+/// Parses the input and solves each of the parts, timing them and measuring their memory. Each
+/// step runs on a thread of its own, so it can be given up on once it's taken longer than the
+/// timeout, and the threads of any that are given up on are added to `background`.
+pub fn run_day(
+    day: &'static Day,
+    input_text: String,
+    origin: &str,
+    parts: &[Part],
+    timeout: Option<Duration>,
+    background: &mut Vec<JoinHandle<()>>,
+) -> Vec<PartReport> {
+    let parse = day.parse;
+    let start = Instant::now();
+    let parsed = with_timeout(timeout, background, move || {
+        let solution = parse(&input_text).map(Arc::<dyn DynSolution>::from);
+        // The thread is new, so everything it still has in use belongs to the solution
+        (solution, memory::peak(), memory::current())
+    });
+    let parse_time = start.elapsed();
+    let fail_all = |status: RunStatus, error: String| {
+        parts
+            .iter()
+            .map(|&part| PartReport::failed(day.number, part, status, error.clone()))
+            .collect()
+    };
+    let (solution, parse_peak, retained) = match parsed {
+        Ok((Ok(solution), peak, retained)) => (solution, peak, retained),
+        Ok((Err(e), ..)) => {
+            return fail_all(
+                RunStatus::ParseError,
+                format!("Couldn't parse {}: {}", origin, e),
+            )
+        }
+        Err(status) => {
+            return fail_all(
+                status,
+                format!("Parsing {} {}", origin, describe(status, timeout)),
+            )
+        }
+    };
+    log::info!("Parsed day {:02} in {:?}", day.number, parse_time);
+
+    parts
+        .iter()
+        .map(|&part| {
+            let solution = Arc::clone(&solution);
+            let solved = with_timeout(timeout, background, move || {
+                let start = Instant::now();
+                let answer = solution.answer(part);
+                (answer, start.elapsed(), memory::peak())
+            });
+            let (answer, solve_time, solve_peak) = match solved {
+                Ok(solved) => solved,
+                Err(status) => {
+                    let error = format!("Part {} {}", part, describe(status, timeout));
+                    return PartReport::failed(day.number, part, status, error);
+                }
+            };
+            let peak = parse_peak.max(retained + solve_peak);
+            log::info!(
+                "Solved day {:02} part {} in {:?}, with at most {} bytes in use",
+                day.number,
+                part,
+                solve_time,
+                peak
+            );
+            PartReport {
+                day: day.number,
+                part: part.number(),
+                answer: Some(answer),
+                parse_time_ns: Some(parse_time.as_nanos() as u64),
+                solve_time_ns: Some(solve_time.as_nanos() as u64),
+                peak_memory_bytes: Some(peak),
+                status: RunStatus::Solved,
+                error: None,
+            }
+        })
+        .collect()
+}
+
+/// Runs `f` on a thread of its own, giving up on it after the timeout with
+/// [`RunStatus::TimedOut`]. This isn't cancellation: there's no stopping a thread early, so one
+/// that's given up on carries on until it finishes or the runner exits, and its handle is added to
+/// `background` so it can be accounted for.
+fn with_timeout<T: Send + 'static>(
+    timeout: Option<Duration>,
+    background: &mut Vec<JoinHandle<()>>,
+    f: impl FnOnce() -> T + Send + 'static,
+) -> Result<T, RunStatus> {
+    let (sender, receiver) = mpsc::channel();
+    let handle = thread::spawn(move || {
+        let _ = sender.send(f());
+    });
+    let received = match timeout {
+        Some(timeout) => receiver.recv_timeout(timeout),
+        None => receiver.recv().map_err(mpsc::RecvTimeoutError::from),
+    };
+    received.map_err(|e| match e {
+        mpsc::RecvTimeoutError::Timeout => {
+            background.push(handle);
+            RunStatus::TimedOut
+        }
+        mpsc::RecvTimeoutError::Disconnected => RunStatus::Panicked,
+    })
+}
+
+fn describe(status: RunStatus, timeout: Option<Duration>) -> String {
+    match (status, timeout) {
+        (RunStatus::TimedOut, Some(timeout)) => format!("timed out after {:?}", timeout),
+        _ => "panicked".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::DAYS;
+
+    #[test]
+    fn test_run_day() {
+        let day = &DAYS[0];
+        let example = day.examples.get("example_1");
+        let text = example.text.to_string();
+        let mut background = Vec::new();
+        let reports = run_day(day, text, "example_1", &[Part::One], None, &mut background);
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].status, RunStatus::Solved);
+        assert_eq!(reports[0].answer.as_deref(), example.answer(Part::One));
+        assert!(reports[0].parse_time_ns.is_some());
+
+        let text = "no numbers here".to_string();
+        let reports = run_day(day, text, "stdin", &Part::BOTH, None, &mut background);
+        assert_eq!(reports.len(), 2);
+        assert!(reports
+            .iter()
+            .all(|report| report.status == RunStatus::ParseError && report.answer.is_none()));
+        assert!(reports[1]
+            .error
+            .as_deref()
+            .is_some_and(|error| error.starts_with("Couldn't parse stdin: ")));
+
+        let json = serde_json::to_value(&reports[0]).unwrap();
+        assert_eq!(json["status"], "parse_error");
+        assert_eq!(json["part"], 1);
+        assert!(json["answer"].is_null());
+        assert!(background.is_empty());
+    }
+
+    #[test]
+    fn test_with_timeout() {
+        let timeout = Some(Duration::from_millis(50));
+        let mut background = Vec::new();
+        assert_eq!(with_timeout(timeout, &mut background, || 1 + 1), Ok(2));
+        assert_eq!(
+            with_timeout(None, &mut background, || -> u8 { panic!("on purpose") }),
+            Err(RunStatus::Panicked)
+        );
+        assert!(background.is_empty());
+
+        // Timing out leaves the thread running, rather than stopping it
+        let (sender, receiver) = mpsc::channel();
+        let slow = move || {
+            thread::sleep(Duration::from_millis(200));
+            let _ = sender.send("finished anyway");
+        };
+        assert_eq!(
+            with_timeout(timeout, &mut background, slow),
+            Err(RunStatus::TimedOut)
+        );
+        assert_eq!(background.len(), 1);
+        assert!(!background[0].is_finished());
+        assert_eq!(receiver.recv(), Ok("finished anyway"));
+        background.pop().unwrap().join().unwrap();
+    }
+
+    /// Parses fine, then panics solving either part
+    struct Panics;
+
+    impl DynSolution for Panics {
+        fn answer(&self, _part: Part) -> String {
+            panic!("on purpose")
+        }
+    }
+
+    static PANICS: Day = Day {
+        number: 25,
+        parse: |_| Ok(Box::new(Panics)),
+        examples: aoc_core::Examples {
+            answers: "",
+            inputs: &[],
+        },
+        generator: aoc_core::Generator {
+            size: 0,
+            generate: |_, _| String::new(),
+        },
+    };
+
+    #[test]
+    fn test_run_days_panicking() {
+        let input = Input {
+            text: String::new(),
+            origin: "nothing".to_string(),
+        };
+        let run = run_days(vec![(&PANICS, Ok(input))], &Part::BOTH, None, 1);
+        assert_eq!(run.reports.len(), 2);
+        for report in run.reports {
+            assert_eq!(report.status, RunStatus::Panicked);
+            assert_eq!(report.error, Some(format!("Part {} panicked", report.part)));
+        }
+    }
+
+    #[test]
+    fn test_run_days() {
+        let days = DAYS
+            .iter()
+            .rev()
+            .map(|day| {
+                let input = Input {
+                    text: day.examples.all()[0].text.to_string(),
+                    origin: "example".to_string(),
+                };
+                (day, Ok(input))
+            })
+            .collect();
+        let run = run_days(days, &[Part::One], None, 4);
+        assert_eq!(run.reports.len(), DAYS.len());
+        assert!(run.reports.windows(2).all(|pair| pair[0].day < pair[1].day));
+        assert_eq!(run.still_running, 0);
+    }
+}
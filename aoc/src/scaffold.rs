@@ -0,0 +1,368 @@
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+const CARGO_TEMPLATE: &str = include_str!("../templates/day/Cargo.toml.template");
+const LIB_TEMPLATE: &str = include_str!("../templates/day/lib.rs.template");
+const ERROR_TEMPLATE: &str = include_str!("../templates/day/error.rs.template");
+const GENERATE_TEMPLATE: &str = include_str!("../templates/day/generate.rs.template");
+const FUZZ_TARGET_TEMPLATE: &str = include_str!("../templates/day/fuzz_target.rs.template");
+const EXAMPLE_TEMPLATE: &str = include_str!("../templates/day/example.txt.template");
+const EXAMPLE_ANSWERS_TEMPLATE: &str = include_str!("../templates/day/answers.toml.template");
+
+#[derive(Debug, Error)]
+pub enum ScaffoldError {
+    #[error("{0} already exists")]
+    Exists(String),
+    #[error("couldn't access {path}: {source}")]
+    Io {
+        path: String,
+        source: std::io::Error,
+    },
+    #[error("couldn't find where to add the day in {0}")]
+    NoInsertPoint(String),
+}
+
+/// Fills in the `{{day}}` placeholders of a template, e.g. `Day{{day}}` becomes `Day12`
+pub fn render(template: &str, number: u8) -> String {
+    template.replace("{{day}}", &format!("{:02}", number))
+}
+
+/// Creates the crate for the day from the templates and hooks it up to the workspace, runner,
+/// benchmarks, fuzz targets and answers, returning every file it touched
+///
+/// Either all of it happens or none of it does. Every edit is worked out before anything is
+/// written, the crate is written to a temporary directory and renamed into place, and if writing
+/// one of the other files fails the ones written before it are put back.
+pub fn scaffold(root: &Path, number: u8) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let name = format!("day_{:02}", number);
+    let crate_dir = root.join(&name);
+    if crate_dir.exists() {
+        return Err(ScaffoldError::Exists(crate_dir.display().to_string()));
+    }
+
+    let edits = [
+        edit_file(root.join("Cargo.toml"), |text| add_member(text, &name))?,
+        edit_file(root.join("fuzz/Cargo.toml"), |text| {
+            add_dependency(text, &name).map(|text| add_fuzz_target(&text, &name))
+        })?,
+        edit_file(root.join("aoc/Cargo.toml"), |text| {
+            add_dependency(text, &name)
+        })?,
+        edit_file(root.join("aoc/src/days.rs"), |text| add_day(text, number))?,
+        edit_file(root.join("answers.toml"), |text| {
+            Some(add_answers(text, number))
+        })?,
+    ];
+
+    let mut touched = write_crate(root, &crate_dir, number)?;
+    let fuzz_target = root
+        .join("fuzz")
+        .join("fuzz_targets")
+        .join(format!("parse_{}.rs", name));
+    let mut written = Vec::new();
+    let result = edits
+        .iter()
+        .try_for_each(|edit| {
+            std::fs::write(&edit.path, &edit.edited)
+                .map_err(|source| io_error(&edit.path, source))?;
+            written.push(edit);
+            Ok(())
+        })
+        .and_then(|_| {
+            std::fs::write(&fuzz_target, render(FUZZ_TARGET_TEMPLATE, number))
+                .map_err(|source| io_error(&fuzz_target, source))
+        });
+    if let Err(e) = result {
+        // Best effort, since there's already an error to report
+        for edit in written {
+            let _ = std::fs::write(&edit.path, &edit.original);
+        }
+        let _ = std::fs::remove_file(&fuzz_target);
+        let _ = std::fs::remove_dir_all(&crate_dir);
+        return Err(e);
+    }
+
+    touched.push(fuzz_target);
+    touched.extend(edits.into_iter().map(|edit| edit.path));
+    Ok(touched)
+}
+
+/// Writes the day's own files to a temporary directory next to where the crate goes, then renames
+/// it into place, so there's never a half written crate
+fn write_crate(root: &Path, crate_dir: &Path, number: u8) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let staging = root.join(format!(".day_{:02}.new", number));
+    let files = [
+        ("Cargo.toml", CARGO_TEMPLATE),
+        ("src/lib.rs", LIB_TEMPLATE),
+        ("src/error.rs", ERROR_TEMPLATE),
+        ("src/generate.rs", GENERATE_TEMPLATE),
+        ("examples/example.txt", EXAMPLE_TEMPLATE),
+        ("examples/answers.toml", EXAMPLE_ANSWERS_TEMPLATE),
+    ];
+    let result = files.iter().try_for_each(|(file, template)| {
+        let path = staging.join(file);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|source| io_error(parent, source))?;
+        }
+        std::fs::write(&path, render(template, number)).map_err(|source| io_error(&path, source))
+    });
+    let result = result.and_then(|_| {
+        std::fs::rename(&staging, crate_dir).map_err(|source| io_error(crate_dir, source))
+    });
+    if let Err(e) = result {
+        let _ = std::fs::remove_dir_all(&staging);
+        return Err(e);
+    }
+    Ok(files.iter().map(|(file, _)| crate_dir.join(file)).collect())
+}
+
+/// A file along with how it reads before and after an edit
+struct Edit {
+    path: PathBuf,
+    original: String,
+    edited: String,
+}
+
+/// Works out the edit to the file, which gives `None` when it can't find where to make it
+fn edit_file(path: PathBuf, edit: impl Fn(&str) -> Option<String>) -> Result<Edit, ScaffoldError> {
+    let original = std::fs::read_to_string(&path).map_err(|source| io_error(&path, source))?;
+    let edited =
+        edit(&original).ok_or_else(|| ScaffoldError::NoInsertPoint(path.display().to_string()))?;
+    Ok(Edit {
+        path,
+        original,
+        edited,
+    })
+}
+
+fn io_error(path: &Path, source: std::io::Error) -> ScaffoldError {
+    ScaffoldError::Io {
+        path: path.display().to_string(),
+        source,
+    }
+}
+
+/// Inserts `line` into the lines of `text[start..end]`, before the first one that sorts after it
+fn insert_sorted(text: &str, start: usize, end: usize, line: &str) -> String {
+    let mut offset = start;
+    for existing in text[start..end].split_inclusive('\n') {
+        if existing.trim() > line.trim() {
+            break;
+        }
+        offset += existing.len();
+    }
+    format!("{}{}\n{}", &text[..offset], line, &text[offset..])
+}
+
+/// Adds the crate to the workspace members
+///
+/// # Examples
+///
+/// ```
+/// use aoc::scaffold::add_member;
+///
+/// let manifest = "[workspace]\nmembers = [\n    \"aoc\",\n    \"day_01\",\n]\n";
+/// assert_eq!(
+///     add_member(manifest, "day_02").unwrap(),
+///     "[workspace]\nmembers = [\n    \"aoc\",\n    \"day_01\",\n    \"day_02\",\n]\n"
+/// );
+/// ```
+pub fn add_member(manifest: &str, name: &str) -> Option<String> {
+    let start = manifest.find("members = [\n")? + "members = [\n".len();
+    let end = start + manifest[start..].find(']')?;
+    Some(insert_sorted(
+        manifest,
+        start,
+        end,
+        &format!("    \"{}\",", name),
+    ))
+}
+
+/// Adds the crate as a dependency of the runner, next to the other days
+pub fn add_dependency(manifest: &str, name: &str) -> Option<String> {
+    let start = manifest.find("\nday_")? + 1;
+    let end = start
+        + manifest[start..]
+            .split_inclusive('\n')
+            .take_while(|line| line.starts_with("day_"))
+            .map(str::len)
+            .sum::<usize>();
+    let line = format!("{} = {{ path = \"../{}\" }}", name, name);
+    Some(insert_sorted(manifest, start, end, &line))
+}
+
+/// Adds a target to the fuzz crate that feeds the day's parser arbitrary input
+///
+/// # Examples
+///
+/// ```
+/// use aoc::scaffold::add_fuzz_target;
+///
+/// let manifest = "[[bin]]\nname = \"parse_day_01\"\n";
+/// assert!(add_fuzz_target(manifest, "day_02")
+///     .ends_with("\n\n[[bin]]\nname = \"parse_day_02\"\npath = \"fuzz_targets/parse_day_02.rs\"\ntest = false\ndoc = false\nbench = false\n"));
+/// ```
+pub fn add_fuzz_target(manifest: &str, name: &str) -> String {
+    format!(
+        "{}\n\n[[bin]]\nname = \"parse_{}\"\npath = \"fuzz_targets/parse_{}.rs\"\ntest = false\ndoc = false\nbench = false\n",
+        manifest.trim_end(),
+        name,
+        name
+    )
+}
+
+/// Adds the day to the runner's `DAYS`, which also gives it benchmarks
+pub fn add_day(days: &str, number: u8) -> Option<String> {
+    let start = days.find("pub const DAYS: &[Day] = &[\n")?;
+    let end = start + days[start..].find("\n];")? + 1;
+    let entry = format!(
+        "    Day {{\n        number: {},\n        parse: parse_dyn::<day_{:02}::Day{:02}>,\n        examples: day_{:02}::EXAMPLES,\n        generator: day_{:02}::GENERATOR,\n    }},\n",
+        number, number, number, number, number
+    );
+
+    let later_entry = days[start..end]
+        .match_indices("    Day {\n        number: ")
+        .find(|(i, prefix)| {
+            let number_start = start + i + prefix.len();
+            let digits = days[number_start..].split(',').next().unwrap_or_default();
+            digits.parse::<u8>().is_ok_and(|other| other > number)
+        })
+        .map(|(i, _)| start + i);
+    let offset = later_entry.unwrap_or(end);
+    Some(format!("{}{}{}", &days[..offset], entry, &days[offset..]))
+}
+
+/// Adds a commented out placeholder for the day's answers, to be filled in once they're accepted
+pub fn add_answers(answers: &str, number: u8) -> String {
+    format!(
+        "{}\n\n[day_{:02}]\n# part_1 = \n# part_2 = \n",
+        answers.trim_end(),
+        number
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_dependency() {
+        let manifest = "[dependencies]\nclap = \"4\"\nday_01 = { path = \"../day_01\" }\nday_03 = { path = \"../day_03\" }\n\n[dev-dependencies]\n";
+        assert_eq!(
+            add_dependency(manifest, "day_02").unwrap(),
+            "[dependencies]\nclap = \"4\"\nday_01 = { path = \"../day_01\" }\nday_02 = { path = \"../day_02\" }\nday_03 = { path = \"../day_03\" }\n\n[dev-dependencies]\n"
+        );
+        assert!(add_dependency(manifest, "day_04")
+            .unwrap()
+            .contains("day_03 = { path = \"../day_03\" }\nday_04 = { path = \"../day_04\" }\n\n"));
+    }
+
+    #[test]
+    fn test_add_day() {
+        let days = "pub const DAYS: &[Day] = &[\n    Day {\n        number: 1,\n        parse: parse_dyn::<day_01::Day01>,\n    },\n    Day {\n        number: 12,\n        parse: parse_dyn::<day_12::Day12>,\n    },\n];\n";
+        let added = add_day(days, 2).unwrap();
+        let first = added.find("day_01::Day01").unwrap();
+        let second = added.find("day_02::Day02").unwrap();
+        let twelfth = added.find("day_12::Day12").unwrap();
+        assert!(first < second && second < twelfth);
+
+        let added = add_day(days, 13).unwrap();
+        assert!(added.ends_with(
+            "examples: day_13::EXAMPLES,\n        generator: day_13::GENERATOR,\n    },\n];\n"
+        ));
+    }
+
+    #[test]
+    fn test_add_answers() {
+        assert_eq!(
+            add_answers("[day_01]\npart_1 = 1\n", 2),
+            "[day_01]\npart_1 = 1\n\n[day_02]\n# part_1 = \n# part_2 = \n"
+        );
+    }
+
+    /// A copy of the files the scaffold edits, as small as they can be
+    fn workspace() -> tempfile::TempDir {
+        let root = tempfile::tempdir().unwrap();
+        let files = [
+            (
+                "Cargo.toml",
+                "[workspace]\nmembers = [\n    \"aoc\",\n    \"day_01\",\n]\n",
+            ),
+            (
+                "fuzz/Cargo.toml",
+                "[dependencies]\nday_01 = { path = \"../day_01\" }\n",
+            ),
+            (
+                "aoc/Cargo.toml",
+                "[dependencies]\nday_01 = { path = \"../day_01\" }\n",
+            ),
+            ("aoc/src/days.rs", "pub const DAYS: &[Day] = &[\n];\n"),
+            ("answers.toml", "[day_01]\npart_1 = 1\n"),
+        ];
+        for (file, text) in files {
+            let path = root.path().join(file);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, text).unwrap();
+        }
+        std::fs::create_dir_all(root.path().join("fuzz/fuzz_targets")).unwrap();
+        root
+    }
+
+    #[test]
+    fn test_scaffold() {
+        let root = workspace();
+        let touched = scaffold(root.path(), 2).unwrap();
+        assert_eq!(touched.len(), 12);
+        assert!(root.path().join("day_02/src/lib.rs").exists());
+        assert!(!root.path().join(".day_02.new").exists());
+        assert!(matches!(
+            scaffold(root.path(), 2),
+            Err(ScaffoldError::Exists(_))
+        ));
+    }
+
+    #[test]
+    fn test_scaffold_failure() {
+        // The runner has nowhere to add the day, so nothing else should change either
+        let root = workspace();
+        std::fs::write(root.path().join("aoc/src/days.rs"), "").unwrap();
+        let manifest = std::fs::read_to_string(root.path().join("Cargo.toml")).unwrap();
+        assert!(matches!(
+            scaffold(root.path(), 2),
+            Err(ScaffoldError::NoInsertPoint(_))
+        ));
+        assert!(!root.path().join("day_02").exists());
+        assert!(!root
+            .path()
+            .join("fuzz/fuzz_targets/parse_day_02.rs")
+            .exists());
+        assert_eq!(
+            std::fs::read_to_string(root.path().join("Cargo.toml")).unwrap(),
+            manifest
+        );
+
+        // Writing the fuzz target fails last of all, after the crate and every edit are in place
+        let root = workspace();
+        std::fs::remove_dir(root.path().join("fuzz/fuzz_targets")).unwrap();
+        let manifest = std::fs::read_to_string(root.path().join("Cargo.toml")).unwrap();
+        assert!(matches!(
+            scaffold(root.path(), 2),
+            Err(ScaffoldError::Io { .. })
+        ));
+        assert!(!root.path().join("day_02").exists());
+        assert_eq!(
+            std::fs::read_to_string(root.path().join("Cargo.toml")).unwrap(),
+            manifest
+        );
+    }
+
+    #[test]
+    fn test_templates() {
+        let lib = render(LIB_TEMPLATE, 12);
+        assert!(lib.contains("impl Solution for Day12 {"));
+        assert!(!lib.contains("{{day}}"));
+        assert!(render(CARGO_TEMPLATE, 3).contains("name = \"day_03\""));
+        assert!(render(GENERATE_TEMPLATE, 12).contains("Day12::parse"));
+        assert!(render(FUZZ_TARGET_TEMPLATE, 12).contains("day_12::Day12::parse(input_text)"));
+    }
+}
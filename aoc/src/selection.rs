@@ -0,0 +1,60 @@
+use std::{fmt::Display, ops::RangeInclusive, str::FromStr};
+
+/// Which days to run, parsed from `all`, a single day `7` or an inclusive range `3-7`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaySelection(RangeInclusive<u8>);
+
+impl DaySelection {
+    pub fn contains(&self, day: u8) -> bool {
+        self.0.contains(&day)
+    }
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let parse_day = |s: &str| match s.trim().parse::<u8>() {
+            Ok(day @ 1..=25) => Ok(day),
+            _ => Err(format!("'{}' is not a day between 1 and 25", s)),
+        };
+
+        match input.trim().split_once('-') {
+            _ if input.trim().eq_ignore_ascii_case("all") => Ok(Self(1..=25)),
+            Some((start, end)) => {
+                let (start, end) = (parse_day(start)?, parse_day(end)?);
+                match start <= end {
+                    true => Ok(Self(start..=end)),
+                    false => Err(format!("'{}' is an empty range of days", input)),
+                }
+            }
+            None => parse_day(input).map(|day| Self(day..=day)),
+        }
+    }
+}
+
+impl Display for DaySelection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0.start() == self.0.end() {
+            true => write!(f, "day {}", self.0.start()),
+            false => write!(f, "days {}-{}", self.0.start(), self.0.end()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("all".parse(), Ok(DaySelection(1..=25)));
+        assert_eq!("7".parse(), Ok(DaySelection(7..=7)));
+        assert_eq!("3-7".parse(), Ok(DaySelection(3..=7)));
+        assert_eq!(" 3 - 7 ".parse(), Ok(DaySelection(3..=7)));
+        assert!("0".parse::<DaySelection>().is_err());
+        assert!("26".parse::<DaySelection>().is_err());
+        assert!("7-3".parse::<DaySelection>().is_err());
+        assert!("seven".parse::<DaySelection>().is_err());
+    }
+}
@@ -0,0 +1,463 @@
+use crate::{
+    fetch::{FetchError, Fetcher},
+    YEAR,
+};
+use aoc_core::Part;
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+use thiserror::Error;
+
+/// What the site made of a submitted answer
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without saying which way
+    Wrong,
+    /// Submitted too soon after the last answer, so it wasn't checked
+    Wait {
+        seconds: u64,
+    },
+    /// The part was already solved, so it wasn't checked
+    AlreadySolved,
+    Unknown {
+        message: String,
+    },
+}
+
+impl Verdict {
+    /// Reads the verdict out of the page the site responds to an answer with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::submit::Verdict;
+    ///
+    /// let page = "<article><p>That's not the right answer; your answer is too low.</p></article>";
+    /// assert_eq!(Verdict::parse(page), Verdict::TooLow);
+    /// let page = "<article><p>You gave an answer too recently. You have 1m 5s left to wait.</p></article>";
+    /// assert_eq!(Verdict::parse(page), Verdict::Wait { seconds: 65 });
+    /// ```
+    pub fn parse(page: &str) -> Self {
+        if page.contains("That's the right answer") {
+            Verdict::Correct
+        } else if page.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if page.contains("your answer is too low") {
+            Verdict::TooLow
+        } else if page.contains("That's not the right answer") {
+            Verdict::Wrong
+        } else if page.contains("You gave an answer too recently") {
+            Verdict::Wait {
+                seconds: Verdict::parse_wait(page).unwrap_or(60),
+            }
+        } else if page.contains("Did you already complete it") {
+            Verdict::AlreadySolved
+        } else {
+            Verdict::Unknown {
+                message: article_text(page),
+            }
+        }
+    }
+
+    /// Parses the `1m 5s` out of `You have 1m 5s left to wait`
+    fn parse_wait(page: &str) -> Option<u64> {
+        let start = page.find("You have ")? + "You have ".len();
+        let end = start + page[start..].find(" left to wait")?;
+        page[start..end]
+            .split_whitespace()
+            .map(
+                |amount| match amount.split_at(amount.len().checked_sub(1)?) {
+                    (minutes, "m") => Some(minutes.parse::<u64>().ok()? * 60),
+                    (seconds, "s") => seconds.parse().ok(),
+                    _ => None,
+                },
+            )
+            .sum()
+    }
+
+    /// Whether the site actually judged the answer
+    pub fn is_judged(&self) -> bool {
+        matches!(
+            self,
+            Verdict::Correct | Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong
+        )
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::Wait { seconds } => write!(f, "submitted too soon, wait {}s", seconds),
+            Verdict::AlreadySolved => write!(f, "already solved"),
+            Verdict::Unknown { message } => write!(f, "unrecognised response: {}", message),
+        }
+    }
+}
+
+/// The text of the page's `<article>`, which is where the site puts its message
+fn article_text(page: &str) -> String {
+    let article = match (page.find("<article>"), page.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &page[start..end],
+        _ => page,
+    };
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// An answer that was sent to the site
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Seconds since the Unix epoch
+    pub submitted_at: u64,
+}
+
+/// Why an answer wasn't sent
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum Refusal {
+    #[error("already solved with {0}")]
+    Solved(String),
+    #[error("{answer} was already submitted and was {verdict}")]
+    Duplicate { answer: String, verdict: Verdict },
+    #[error("{answer} can't be right, {bound} was already too high")]
+    AboveTooHigh { answer: String, bound: String },
+    #[error("{answer} can't be right, {bound} was already too low")]
+    BelowTooLow { answer: String, bound: String },
+    #[error("the site asked to wait, try again in {}s", .0.as_secs())]
+    Wait(Duration),
+}
+
+#[derive(Debug, Error)]
+pub enum SubmitError {
+    #[error("refusing to submit: {0}")]
+    Refused(#[from] Refusal),
+    #[error(transparent)]
+    Fetch(#[from] FetchError),
+    #[error("couldn't access the history at {path}: {message}")]
+    History { path: String, message: String },
+}
+
+/// Every answer submitted so far, saved so wrong guesses aren't repeated
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct History {
+    #[serde(default, rename = "submission")]
+    pub submissions: Vec<Submission>,
+}
+
+impl History {
+    /// Where the history is kept, alongside the cached inputs
+    pub fn path(cache_dir: &Path) -> PathBuf {
+        cache_dir.join(YEAR.to_string()).join("submissions.toml")
+    }
+
+    /// Loads the history, which is empty if nothing has been submitted yet
+    pub fn load(path: &Path) -> Result<Self, SubmitError> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => toml::from_str(&text).map_err(|e| history_error(path, e)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(history_error(path, e)),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), SubmitError> {
+        let text = toml::to_string(self).map_err(|e| history_error(path, e))?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| history_error(path, e))?;
+        }
+        std::fs::write(path, text).map_err(|e| history_error(path, e))
+    }
+
+    /// Refuses answers the history already shows are pointless to send
+    pub fn check(&self, day: u8, part: Part, answer: &str, now: u64) -> Result<(), Refusal> {
+        if let Some(wait) = self
+            .submissions
+            .iter()
+            .filter_map(|s| match s.verdict {
+                Verdict::Wait { seconds } => Some(s.submitted_at + seconds),
+                _ => None,
+            })
+            .max()
+            .filter(|until| *until > now)
+        {
+            return Err(Refusal::Wait(Duration::from_secs(wait - now)));
+        }
+
+        let previous = || {
+            self.submissions
+                .iter()
+                .filter(move |s| s.day == day && s.part == part.number())
+        };
+        for submission in previous() {
+            if submission.verdict == Verdict::Correct {
+                return Err(Refusal::Solved(submission.answer.clone()));
+            }
+            if submission.answer == answer && submission.verdict.is_judged() {
+                return Err(Refusal::Duplicate {
+                    answer: answer.to_string(),
+                    verdict: submission.verdict.clone(),
+                });
+            }
+        }
+
+        let Ok(value) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+        // The numeric answers that got the verdict, alongside their value
+        let bounds = |verdict: Verdict| {
+            previous()
+                .filter(move |s| s.verdict == verdict)
+                .filter_map(|s| Some((s.answer.parse::<i128>().ok()?, &s.answer)))
+        };
+        let too_high = bounds(Verdict::TooHigh).min_by_key(|(bound, _)| *bound);
+        if let Some((_, bound)) = too_high.filter(|(bound, _)| value >= *bound) {
+            return Err(Refusal::AboveTooHigh {
+                answer: answer.to_string(),
+                bound: bound.clone(),
+            });
+        }
+        let too_low = bounds(Verdict::TooLow).max_by_key(|(bound, _)| *bound);
+        if let Some((_, bound)) = too_low.filter(|(bound, _)| value <= *bound) {
+            return Err(Refusal::BelowTooLow {
+                answer: answer.to_string(),
+                bound: bound.clone(),
+            });
+        }
+        Ok(())
+    }
+}
+
+fn history_error(path: &Path, e: impl Display) -> SubmitError {
+    SubmitError::History {
+        path: path.display().to_string(),
+        message: e.to_string(),
+    }
+}
+
+/// Sends the answer unless the history refuses it, recording whatever the site says
+pub fn submit(
+    fetcher: &Fetcher,
+    history: &mut History,
+    day: u8,
+    part: Part,
+    answer: &str,
+    now: u64,
+) -> Result<Verdict, SubmitError> {
+    history.check(day, part, answer, now)?;
+
+    let session = fetcher.session()?;
+    let url = format!("{}/{}/day/{}/answer", fetcher.base_url(), YEAR, day);
+    fetcher.throttle()?;
+    log::info!("Submitting {} to {}", answer, url);
+    let level = part.number().to_string();
+    let response =
+        fetcher
+            .client()
+            .post(&url, session, &[("level", &level), ("answer", answer)])?;
+    let verdict = match response.status {
+        200 => Verdict::parse(&response.body),
+        status => {
+            return Err(SubmitError::Fetch(FetchError::Status {
+                status,
+                body: article_text(&response.body),
+            }))
+        }
+    };
+
+    history.submissions.push(Submission {
+        day,
+        part: part.number(),
+        answer: answer.to_string(),
+        verdict: verdict.clone(),
+        submitted_at: now,
+    });
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fetch::UreqClient, test_server};
+
+    fn submission(part: u8, answer: &str, verdict: Verdict) -> Submission {
+        Submission {
+            day: 7,
+            part,
+            answer: answer.to_string(),
+            verdict,
+            submitted_at: 1000,
+        }
+    }
+
+    #[test]
+    fn test_parse_verdicts() {
+        let page = |message: &str| format!("<main><article><p>{}</p></article></main>", message);
+        assert_eq!(
+            Verdict::parse(&page(
+                "That's the right answer!  You are <span>one gold star</span> closer."
+            )),
+            Verdict::Correct
+        );
+        assert_eq!(
+            Verdict::parse(&page(
+                "That's not the right answer; your answer is too high."
+            )),
+            Verdict::TooHigh
+        );
+        assert_eq!(
+            Verdict::parse(&page("That's not the right answer.  If you're stuck, make sure you're using the full input")),
+            Verdict::Wrong
+        );
+        assert_eq!(
+            Verdict::parse(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 37s left to wait.")),
+            Verdict::Wait { seconds: 37 }
+        );
+        assert_eq!(
+            Verdict::parse(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            )),
+            Verdict::AlreadySolved
+        );
+        assert_eq!(
+            Verdict::parse(&page("Something <em>new</em>")),
+            Verdict::Unknown {
+                message: "Something new".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_refusals() {
+        let history = History {
+            submissions: vec![
+                submission(1, "100", Verdict::TooLow),
+                submission(1, "120", Verdict::TooLow),
+                submission(1, "200", Verdict::TooHigh),
+                submission(1, "150", Verdict::Wrong),
+                submission(1, "160", Verdict::Wait { seconds: 60 }),
+                submission(2, "5", Verdict::Correct),
+            ],
+        };
+
+        assert_eq!(
+            history.check(7, Part::One, "130", 1030),
+            Err(Refusal::Wait(Duration::from_secs(30)))
+        );
+        assert_eq!(history.check(7, Part::One, "130", 2000), Ok(()));
+        // Waiting meant it was never checked, so it can be sent again
+        assert_eq!(history.check(7, Part::One, "160", 2000), Ok(()));
+        assert_eq!(
+            history.check(7, Part::One, "150", 2000),
+            Err(Refusal::Duplicate {
+                answer: "150".to_string(),
+                verdict: Verdict::Wrong
+            })
+        );
+        assert_eq!(
+            history.check(7, Part::One, "110", 2000),
+            Err(Refusal::BelowTooLow {
+                answer: "110".to_string(),
+                bound: "120".to_string()
+            })
+        );
+        assert_eq!(
+            history.check(7, Part::One, "200", 2000),
+            Err(Refusal::Duplicate {
+                answer: "200".to_string(),
+                verdict: Verdict::TooHigh
+            })
+        );
+        assert_eq!(
+            history.check(7, Part::One, "250", 2000),
+            Err(Refusal::AboveTooHigh {
+                answer: "250".to_string(),
+                bound: "200".to_string()
+            })
+        );
+        assert_eq!(
+            history.check(7, Part::Two, "6", 2000),
+            Err(Refusal::Solved("5".to_string()))
+        );
+        assert_eq!(history.check(8, Part::One, "250", 2000), Ok(()));
+
+        let dir = tempfile::tempdir().unwrap();
+        let mut saved = history.clone();
+        saved.submissions.push(submission(
+            1,
+            "7",
+            Verdict::Unknown {
+                message: "Something new".to_string(),
+            },
+        ));
+        saved.save(&dir.path().join("submissions.toml")).unwrap();
+        assert_eq!(
+            History::load(&dir.path().join("submissions.toml")).unwrap(),
+            saved
+        );
+    }
+
+    #[test]
+    fn test_submit() {
+        let (base_url, requests) = test_server::serve(|request| match request.body.as_str() {
+            "level=2&answer=42" => (
+                200,
+                "<article><p>That's the right answer!</p></article>".to_string(),
+            ),
+            _ => (
+                200,
+                "<article><p>That's not the right answer; your answer is too low.</p></article>"
+                    .to_string(),
+            ),
+        });
+        let cache = tempfile::tempdir().unwrap();
+        let fetcher = Fetcher::new(Box::<UreqClient>::default(), &base_url, cache.path())
+            .with_session("abc")
+            .with_min_interval(Duration::ZERO);
+        let mut history = History::default();
+
+        assert_eq!(
+            submit(&fetcher, &mut history, 7, Part::Two, "41", 1000).unwrap(),
+            Verdict::TooLow
+        );
+        assert!(matches!(
+            submit(&fetcher, &mut history, 7, Part::Two, "40", 1100),
+            Err(SubmitError::Refused(Refusal::BelowTooLow { .. }))
+        ));
+        assert_eq!(
+            submit(&fetcher, &mut history, 7, Part::Two, "42", 1200).unwrap(),
+            Verdict::Correct
+        );
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].method, "POST");
+        assert_eq!(requests[1].path, "/2023/day/7/answer");
+        assert_eq!(requests[1].cookie.as_deref(), Some("session=abc"));
+
+        let path = History::path(cache.path());
+        history.save(&path).unwrap();
+        assert_eq!(History::load(&path).unwrap(), history);
+    }
+}
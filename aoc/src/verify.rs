@@ -0,0 +1,217 @@
+use crate::{answers::Answers, days::Day};
+use aoc_core::{DynError, Example, Part};
+use std::{fmt::Display, process::ExitCode, time::Duration, time::Instant};
+
+/// How an answer compares to the recorded one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(match self {
+            Status::Pass => "pass",
+            Status::Fail { .. } => "FAIL",
+            Status::Missing => "missing",
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PartCheck {
+    pub part: Part,
+    pub answer: String,
+    pub time: Duration,
+    pub status: Status,
+}
+
+#[derive(Debug, Clone)]
+pub struct DayCheck {
+    pub number: u8,
+    pub parse_time: Duration,
+    pub parts: Vec<PartCheck>,
+}
+
+impl DayCheck {
+    /// Whether no part disagreed with its recorded answer
+    pub fn passed(&self) -> bool {
+        self.parts
+            .iter()
+            .all(|part| !matches!(part.status, Status::Fail { .. }))
+    }
+}
+
+/// How many parts passed, failed or had no answer to compare with, across every day checked
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Summary {
+    pub passed: usize,
+    pub failed: usize,
+    pub missing: usize,
+    /// Days that couldn't be checked at all, like when their input couldn't be read or parsed
+    pub errors: usize,
+}
+
+impl Summary {
+    pub fn add(&mut self, check: &DayCheck) {
+        for part in check.parts.iter() {
+            match part.status {
+                Status::Pass => self.passed += 1,
+                Status::Fail { .. } => self.failed += 1,
+                Status::Missing => self.missing += 1,
+            }
+        }
+    }
+
+    /// Fails if any part disagreed with its recorded answer or any day couldn't be checked, while
+    /// parts without a recorded answer don't count against it
+    pub fn exit_code(&self) -> ExitCode {
+        match self.failed + self.errors {
+            0 => ExitCode::SUCCESS,
+            _ => ExitCode::FAILURE,
+        }
+    }
+}
+
+impl Display for Summary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} passed, {} failed, {} missing",
+            self.passed, self.failed, self.missing
+        )
+    }
+}
+
+/// Solves both parts of the day and compares them with the recorded answers, timing each step
+pub fn check(day: &Day, input_text: &str, answers: &Answers) -> Result<DayCheck, DynError> {
+    check_parts(day, input_text, &Part::BOTH, |part| {
+        answers.get(day.number, part).map(str::to_string)
+    })
+}
+
+/// Solves the parts the example has answers for and compares them with those answers
+pub fn check_example(day: &Day, example: &Example) -> Result<DayCheck, DynError> {
+    check_parts(day, example.text, &example.parts(), |part| {
+        example.answer(part).map(str::to_string)
+    })
+}
+
+fn check_parts(
+    day: &Day,
+    input_text: &str,
+    parts: &[Part],
+    expected: impl Fn(Part) -> Option<String>,
+) -> Result<DayCheck, DynError> {
+    let start = Instant::now();
+    let solution = (day.parse)(input_text)?;
+    let parse_time = start.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = solution.answer(part);
+            let time = start.elapsed();
+            let status = match expected(part) {
+                None => Status::Missing,
+                Some(expected) if expected == answer => Status::Pass,
+                Some(expected) => Status::Fail { expected },
+            };
+            PartCheck {
+                part,
+                answer,
+                time,
+                status,
+            }
+        })
+        .collect();
+
+    Ok(DayCheck {
+        number: day.number,
+        parse_time,
+        parts,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::DAYS;
+
+    fn day(number: u8) -> &'static Day {
+        DAYS.iter().find(|day| day.number == number).unwrap()
+    }
+
+    #[test]
+    fn test_check() {
+        let day = day(7);
+        let input_text = day.examples.input("example");
+
+        let answers = Answers::parse("[day_07]\npart_1 = 6440\npart_2 = 5905").unwrap();
+        let checked = check(day, input_text, &answers).unwrap();
+        let statuses: Vec<_> = checked.parts.iter().map(|part| &part.status).collect();
+        assert_eq!(statuses, [&Status::Pass, &Status::Pass]);
+        assert!(checked.passed());
+
+        let answers = Answers::parse("[day_07]\npart_1 = 6441").unwrap();
+        let checked = check(day, input_text, &answers).unwrap();
+        assert_eq!(
+            checked.parts[0].status,
+            Status::Fail {
+                expected: "6441".to_string()
+            }
+        );
+        assert_eq!(checked.parts[0].answer, "6440");
+        assert_eq!(checked.parts[1].status, Status::Missing);
+        assert!(!checked.passed());
+
+        assert!(check(day, "not a hand", &Answers::default()).is_err());
+    }
+
+    #[test]
+    fn test_summary() {
+        let day = day(7);
+        let input_text = day.examples.input("example");
+        let summarise = |answers: &str| {
+            let answers = Answers::parse(answers).unwrap();
+            let mut summary = Summary::default();
+            summary.add(&check(day, input_text, &answers).unwrap());
+            summary
+        };
+
+        let summary = summarise("[day_07]\npart_1 = 6440");
+        assert_eq!((summary.passed, summary.failed, summary.missing), (1, 0, 1));
+        assert_eq!(summary.exit_code(), ExitCode::SUCCESS);
+        assert_eq!(summary.to_string(), "1 passed, 0 failed, 1 missing");
+
+        let summary = summarise("[day_07]\npart_1 = 6440\npart_2 = 0");
+        assert_eq!((summary.passed, summary.failed, summary.missing), (1, 1, 0));
+        assert_eq!(summary.exit_code(), ExitCode::FAILURE);
+
+        let errors = Summary {
+            errors: 1,
+            ..Summary::default()
+        };
+        assert_eq!(errors.exit_code(), ExitCode::FAILURE);
+    }
+
+    #[test]
+    fn test_examples() {
+        for day in DAYS {
+            let examples = day.examples.all();
+            for example in examples.iter().filter(|e| !e.parts().is_empty()) {
+                let check = check_example(day, example).unwrap();
+                assert!(
+                    check.passed(),
+                    "Day {} {}: {:?}",
+                    day.number,
+                    example.name,
+                    check.parts
+                );
+            }
+        }
+    }
+}
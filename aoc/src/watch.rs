@@ -0,0 +1,288 @@
+//! `aoc watch`, which rebuilds a day and re-runs its tests and parts whenever its sources, examples
+//! or input change
+
+use crate::report::PartReport;
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::{
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    sync::mpsc,
+    time::Duration,
+};
+use thiserror::Error;
+
+/// How long to wait for more changes after the first, as editors and `git checkout` often write
+/// several files in quick succession
+const SETTLE_TIME: Duration = Duration::from_millis(200);
+
+#[derive(Debug, Error)]
+pub enum WatchError {
+    #[error("couldn't watch {path}: {source}")]
+    Watch { path: String, source: notify::Error },
+    #[error("stopped getting changes: {0}")]
+    Stopped(String),
+    #[error("couldn't run cargo: {0}")]
+    Cargo(std::io::Error),
+    #[error("couldn't build the runner")]
+    Build,
+    #[error("couldn't read the runner's answers: {0}")]
+    Json(serde_json::Error),
+}
+
+/// The files a day's answers depend on
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WatchedDay {
+    pub number: u8,
+    pub root: PathBuf,
+    pub crate_dir: PathBuf,
+    /// Every file that could hold the input, whether or not it exists yet
+    pub inputs: Vec<PathBuf>,
+}
+
+impl WatchedDay {
+    pub fn new(root: &Path, number: u8, inputs: Vec<PathBuf>) -> Self {
+        Self {
+            number,
+            root: root.to_path_buf(),
+            crate_dir: root.join(format!("day_{:02}", number)),
+            inputs,
+        }
+    }
+
+    /// Whether a change to the file could change the day's tests or answers. The crate's own
+    /// `target` directory, which it has while it's outside the workspace, is left out so building
+    /// it doesn't set off another run.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::watch::WatchedDay;
+    /// use std::path::Path;
+    ///
+    /// let day = WatchedDay::new(Path::new("/aoc"), 10, vec!["/cache/day_10.txt".into()]);
+    /// assert!(day.is_relevant(Path::new("/aoc/day_10/src/part_one.rs")));
+    /// assert!(day.is_relevant(Path::new("/cache/day_10.txt.gz")));
+    /// assert!(!day.is_relevant(Path::new("/aoc/day_10/target/debug/libday_10.rlib")));
+    /// assert!(!day.is_relevant(Path::new("/cache/day_11.txt")));
+    /// ```
+    pub fn is_relevant(&self, path: &Path) -> bool {
+        let in_crate = |name: &str| path.starts_with(self.crate_dir.join(name));
+        let is_input = |input: &PathBuf| {
+            path == input || path.as_os_str() == format!("{}.gz", input.display()).as_str()
+        };
+        in_crate("src")
+            || in_crate("examples")
+            || in_crate("Cargo.toml")
+            || in_crate("input.txt")
+            || in_crate("input.txt.gz")
+            || self.inputs.iter().any(is_input)
+    }
+
+    /// The crate's directory, along with the directories of whichever of the inputs outside of it
+    /// exist, as there's no watching a file that isn't there yet
+    fn watched_dirs(&self) -> Vec<(PathBuf, RecursiveMode)> {
+        let mut dirs = vec![(self.crate_dir.clone(), RecursiveMode::Recursive)];
+        for input in self.inputs.iter() {
+            let Some(dir) = input.parent() else {
+                continue;
+            };
+            let watched = dirs.iter().any(|(watched, _)| dir.starts_with(watched));
+            if !watched && dir.is_dir() {
+                dirs.push((dir.to_path_buf(), RecursiveMode::NonRecursive));
+            }
+        }
+        dirs
+    }
+
+    /// Runs the day's tests, printing their output as they go, and gives whether they passed. The
+    /// manifest is given rather than the package, so days outside the workspace can be tested too.
+    pub fn test(&self) -> Result<bool, WatchError> {
+        let status = Command::new(cargo())
+            .arg("test")
+            .arg("--quiet")
+            .arg("--manifest-path")
+            .arg(self.crate_dir.join("Cargo.toml"))
+            .status()
+            .map_err(WatchError::Cargo)?;
+        Ok(status.success())
+    }
+
+    /// Rebuilds the runner with the day's latest changes, and solves both parts with it. The runner
+    /// gets a target directory of its own, since some platforms won't let the running `aoc` be
+    /// replaced.
+    pub fn solve(&self) -> Result<Vec<PartReport>, WatchError> {
+        let output = Command::new(cargo())
+            .current_dir(&self.root)
+            .args(["run", "--quiet", "--release", "--package", "aoc"])
+            .arg("--target-dir")
+            .arg(self.root.join("target").join("watch"))
+            .args(["--", "run", &self.number.to_string(), "--format", "json"])
+            .stderr(Stdio::inherit())
+            .output()
+            .map_err(WatchError::Cargo)?;
+        // The runner fails when a part goes unsolved, but still gives its answers
+        if output.stdout.is_empty() {
+            return Err(WatchError::Build);
+        }
+        serde_json::from_slice(&output.stdout).map_err(WatchError::Json)
+    }
+}
+
+/// The cargo that's running the runner, if any, so watching uses the same toolchain
+fn cargo() -> String {
+    std::env::var("CARGO").unwrap_or("cargo".to_string())
+}
+
+/// Tells when any of a day's files change
+pub struct DayWatcher {
+    day: WatchedDay,
+    events: mpsc::Receiver<notify::Result<Event>>,
+    // Changes stop coming once it's dropped
+    _watcher: RecommendedWatcher,
+}
+
+impl DayWatcher {
+    pub fn start(day: WatchedDay) -> Result<Self, WatchError> {
+        let (sender, events) = mpsc::channel();
+        let mut watcher =
+            notify::recommended_watcher(sender).map_err(|source| WatchError::Watch {
+                path: day.crate_dir.display().to_string(),
+                source,
+            })?;
+        for (dir, mode) in day.watched_dirs() {
+            log::info!("Watching {}", dir.display());
+            watcher
+                .watch(&dir, mode)
+                .map_err(|source| WatchError::Watch {
+                    path: dir.display().to_string(),
+                    source,
+                })?;
+        }
+        Ok(Self {
+            day,
+            events,
+            _watcher: watcher,
+        })
+    }
+
+    /// Waits until any of the day's files change, then for the changes to settle, giving every file
+    /// that changed
+    pub fn next_change(&self) -> Result<Vec<PathBuf>, WatchError> {
+        let mut changed = Vec::new();
+        while changed.is_empty() {
+            let event = self
+                .events
+                .recv()
+                .map_err(|e| WatchError::Stopped(e.to_string()))?;
+            self.add_changes(event, &mut changed)?;
+        }
+        while let Ok(event) = self.events.recv_timeout(SETTLE_TIME) {
+            self.add_changes(event, &mut changed)?;
+        }
+        Ok(changed)
+    }
+
+    fn add_changes(
+        &self,
+        event: notify::Result<Event>,
+        changed: &mut Vec<PathBuf>,
+    ) -> Result<(), WatchError> {
+        let event = event.map_err(|e| WatchError::Stopped(e.to_string()))?;
+        // Reading a file doesn't change it
+        if matches!(event.kind, EventKind::Access(_)) {
+            return Ok(());
+        }
+        for path in event.paths {
+            if self.day.is_relevant(&path) && !changed.contains(&path) {
+                log::debug!("{} changed", path.display());
+                changed.push(path);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// How a part's answer compares to the one from the previous run
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Change {
+    /// There was no previous run to compare to
+    First,
+    Same,
+    /// The answer is different, or the part was solved one time and not the other
+    Changed {
+        was: Option<String>,
+    },
+}
+
+/// Compares the part's answer to the same part's in the previous run
+///
+/// # Examples
+///
+/// ```
+/// use aoc::report::{PartReport, RunStatus};
+/// use aoc::watch::{compare, Change};
+/// use aoc_core::Part;
+///
+/// let unsolved = PartReport::failed(7, Part::One, RunStatus::TimedOut, "too slow".to_string());
+/// let solved = PartReport {
+///     answer: Some("6440".to_string()),
+///     status: RunStatus::Solved,
+///     error: None,
+///     ..unsolved.clone()
+/// };
+/// assert_eq!(compare(None, &solved), Change::First);
+/// assert_eq!(compare(Some(&[solved.clone()]), &solved), Change::Same);
+/// assert_eq!(compare(Some(&[unsolved]), &solved), Change::Changed { was: None });
+/// ```
+pub fn compare(previous: Option<&[PartReport]>, report: &PartReport) -> Change {
+    let Some(previous) = previous else {
+        return Change::First;
+    };
+    let was = previous
+        .iter()
+        .find(|previous| previous.day == report.day && previous.part == report.part)
+        .and_then(|previous| previous.answer.clone());
+    match was == report.answer {
+        true => Change::Same,
+        false => Change::Changed { was },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_watched_dirs() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = dir.path().join("cache");
+        std::fs::create_dir(&cache).unwrap();
+        let inputs = vec![
+            dir.path().join("day_10").join("input.txt"),
+            cache.join("day_10.txt"),
+            dir.path().join("missing").join("day_10.txt"),
+        ];
+        let day = WatchedDay::new(dir.path(), 10, inputs);
+        assert_eq!(
+            day.watched_dirs(),
+            vec![
+                (dir.path().join("day_10"), RecursiveMode::Recursive),
+                (cache, RecursiveMode::NonRecursive),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_next_change() {
+        let dir = tempfile::tempdir().unwrap();
+        let src = dir.path().join("day_10").join("src");
+        std::fs::create_dir_all(&src).unwrap();
+        let day = WatchedDay::new(dir.path(), 10, Vec::new());
+        let watcher = DayWatcher::start(day).unwrap();
+
+        std::fs::create_dir(dir.path().join("day_10").join("target")).unwrap();
+        std::fs::write(src.join("lib.rs"), "pub mod part_one;\n").unwrap();
+        let changed = watcher.next_change().unwrap();
+        assert_eq!(changed, vec![src.join("lib.rs")]);
+    }
+}
@@ -0,0 +1,153 @@
+//! The example inputs from the puzzle descriptions, kept as files next to each day so the tests and
+//! `aoc run --example` share a single copy of them.
+//!
+//! Each day keeps its examples in `examples/<name>.txt`, with the answers the puzzle gives for them
+//! in `examples/answers.toml`, laid out like the workspace's `answers.toml`:
+//!
+//! ```toml
+//! [example]
+//! part_1 = 35
+//! part_2 = 46
+//! ```
+//!
+//! An example can leave out a part, as some puzzles give a different example for each part.
+
+use crate::Part;
+
+/// Embeds the named examples of the calling crate, along with its `examples/answers.toml`
+///
+/// # Examples
+///
+/// ```ignore
+/// pub const EXAMPLES: aoc_core::Examples = aoc_core::examples!["example_1", "example_2"];
+/// ```
+#[macro_export]
+macro_rules! examples {
+    ($($name:literal),* $(,)?) => {
+        $crate::Examples {
+            answers: include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/examples/answers.toml")),
+            inputs: &[$(
+                ($name, include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/examples/", $name, ".txt"))),
+            )*],
+        }
+    };
+}
+
+/// A day's examples, usually built by [`examples!`]
+#[derive(Debug, Clone, Copy)]
+pub struct Examples {
+    /// The text of `examples/answers.toml`
+    pub answers: &'static str,
+    /// The text of each `examples/<name>.txt` by its name
+    pub inputs: &'static [(&'static str, &'static str)],
+}
+
+/// A single example and the answers the puzzle gives for it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub name: &'static str,
+    pub text: &'static str,
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+impl Example {
+    /// The parts the puzzle gives answers for, which are the only ones worth solving
+    pub fn parts(&self) -> Vec<Part> {
+        Part::BOTH
+            .into_iter()
+            .filter(|part| self.answer(*part).is_some())
+            .collect()
+    }
+
+    pub fn answer(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part_one.as_deref(),
+            Part::Two => self.part_two.as_deref(),
+        }
+    }
+}
+
+impl Examples {
+    /// Every example along with its answers
+    ///
+    /// # Panics
+    ///
+    /// If `answers.toml` isn't valid TOML, or gives an answer that isn't a string or an integer
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_core::{Examples, Part};
+    ///
+    /// let examples = Examples {
+    ///     answers: "[first]\npart_1 = 142\n\n[second]\npart_2 = \"281\"",
+    ///     inputs: &[("first", "1abc2"), ("second", "two1nine")],
+    /// };
+    /// let all = examples.all();
+    /// assert_eq!(all[0].answer(Part::One), Some("142"));
+    /// assert_eq!(all[0].answer(Part::Two), None);
+    /// assert_eq!(all[1].text, "two1nine");
+    /// assert_eq!(all[1].answer(Part::Two), Some("281"));
+    /// ```
+    pub fn all(&self) -> Vec<Example> {
+        let answers: toml::Table = self
+            .answers
+            .parse()
+            .unwrap_or_else(|e| panic!("examples/answers.toml isn't valid: {}", e));
+        self.inputs
+            .iter()
+            .map(|&(name, text)| {
+                let answer = |part: Part| {
+                    let value = answers.get(name)?.get(format!("part_{}", part))?;
+                    match value {
+                        toml::Value::Integer(n) => Some(n.to_string()),
+                        toml::Value::String(s) => Some(s.clone()),
+                        _ => panic!(
+                            "examples/answers.toml: {}.part_{} isn't an answer",
+                            name, part
+                        ),
+                    }
+                };
+                Example {
+                    name,
+                    text,
+                    part_one: answer(Part::One),
+                    part_two: answer(Part::Two),
+                }
+            })
+            .collect()
+    }
+
+    /// The example with the given name
+    ///
+    /// # Panics
+    ///
+    /// If there's no such example, which is meant for tests that know their examples
+    pub fn get(&self, name: &str) -> Example {
+        self.all()
+            .into_iter()
+            .find(|example| example.name == name)
+            .unwrap_or_else(|| panic!("There's no example named {}", name))
+    }
+
+    /// The input text of the example with the given name
+    pub fn input(&self, name: &str) -> &'static str {
+        self.get(name).text
+    }
+
+    /// The answer the puzzle gives for the example, parsed into the solution's answer type
+    ///
+    /// # Panics
+    ///
+    /// If the example has no answer for the part, or it doesn't parse
+    pub fn answer<T: std::str::FromStr>(&self, name: &str, part: Part) -> T {
+        let example = self.get(name);
+        let answer = example
+            .answer(part)
+            .unwrap_or_else(|| panic!("Example {} has no answer for part {}", name, part));
+        answer
+            .parse()
+            .unwrap_or_else(|_| panic!("Example {} has an unexpected answer: {}", name, answer))
+    }
+}
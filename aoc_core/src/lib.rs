@@ -0,0 +1,244 @@
+//! Shared pieces that every day of Advent of Code 2023 builds on, so tooling such as the runner can work over all of the days generically.
+
+use std::{error::Error, fmt::Display};
+
+pub mod examples;
+pub use examples::{Example, Examples};
+
+/// A boxed error from any day, so errors of different days can be reported side by side
+pub type DynError = Box<dyn Error + Send + Sync>;
+
+/// Which half of a day's puzzle to solve
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    /// Gets the part from its puzzle number
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_core::Part;
+    /// assert_eq!(Part::from_number(1), Some(Part::One));
+    /// assert_eq!(Part::from_number(2), Some(Part::Two));
+    /// assert_eq!(Part::from_number(3), None);
+    /// ```
+    pub fn from_number(number: u8) -> Option<Self> {
+        match number {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// Where in the input text a parser gave up, counted from 1 like a text editor would
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Location {
+    pub fn new(line: usize, column: usize) -> Self {
+        Self { line, column }
+    }
+
+    /// Locates `fragment`, which should be a slice of `text`. Falls back to searching for it, and then the start of `text`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_core::Location;
+    /// let text = "seeds: 79 14\nsoil: 50 98";
+    /// let soil = text.split_ascii_whitespace().nth(4).unwrap();
+    /// assert_eq!(Location::of(text, soil), Location::new(2, 7));
+    /// assert_eq!(Location::of(text, "14"), Location::new(1, 11));
+    /// assert_eq!(Location::of(text, "nowhere"), Location::new(1, 1));
+    /// ```
+    pub fn of(text: &str, fragment: &str) -> Self {
+        let offset = (fragment.as_ptr() as usize)
+            .checked_sub(text.as_ptr() as usize)
+            .filter(|offset| offset + fragment.len() <= text.len())
+            .or_else(|| text.find(fragment))
+            .unwrap_or(0);
+        Self::from_offset(text, offset)
+    }
+
+    /// Locates the byte `offset` within `text`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_core::Location;
+    /// assert_eq!(Location::from_offset("ab\ncd", 0), Location::new(1, 1));
+    /// assert_eq!(Location::from_offset("ab\ncd", 4), Location::new(2, 2));
+    /// ```
+    pub fn from_offset(text: &str, offset: usize) -> Self {
+        let offset = match text.is_char_boundary(offset) {
+            true => offset,
+            false => 0,
+        };
+        let before = &text[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        }
+    }
+}
+
+impl Display for Location {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+/// Errors that point at a [`Location`] in the input text
+pub trait Located: Sized {
+    fn location_mut(&mut self) -> &mut Location;
+
+    /// Moves the error onto the given line, for errors from parsers that were only given a single line
+    fn on_line(mut self, line: usize) -> Self {
+        self.location_mut().line = line;
+        self
+    }
+}
+
+/// A day's puzzle, parsed once from the input text and then solved for either part
+///
+/// # Examples
+///
+/// ```
+/// use aoc_core::Solution;
+///
+/// struct Sum(Vec<i32>);
+///
+/// impl Solution for Sum {
+///     type PartOne = i32;
+///     type PartTwo = i32;
+///     type Error = std::num::ParseIntError;
+///
+///     fn parse(input_text: &str) -> Result<Self, Self::Error> {
+///         let numbers = input_text.lines().map(|l| l.parse()).collect::<Result<_, _>>()?;
+///         Ok(Self(numbers))
+///     }
+///
+///     fn part_one(&self) -> i32 {
+///         self.0.iter().sum()
+///     }
+///
+///     fn part_two(&self) -> i32 {
+///         self.0.iter().product()
+///     }
+/// }
+///
+/// let sum = Sum::parse("2\n3\n4").unwrap();
+/// assert_eq!(sum.part_one(), 9);
+/// assert_eq!(sum.part_two(), 24);
+/// assert!(Sum::parse("2\nthree").is_err());
+/// ```
+pub trait Solution: Sized {
+    type PartOne: Display;
+    type PartTwo: Display;
+    type Error: Error + Send + Sync + 'static;
+
+    fn parse(input_text: &str) -> Result<Self, Self::Error>;
+    fn part_one(&self) -> Self::PartOne;
+    fn part_two(&self) -> Self::PartTwo;
+}
+
+/// Object safe view of a parsed [`Solution`], so days with different answer types can be stored side
+/// by side, and solved on other threads
+pub trait DynSolution: Send + Sync {
+    fn answer(&self, part: Part) -> String;
+}
+
+impl<S: Solution + Send + Sync> DynSolution for S {
+    fn answer(&self, part: Part) -> String {
+        match part {
+            Part::One => self.part_one().to_string(),
+            Part::Two => self.part_two().to_string(),
+        }
+    }
+}
+
+/// Makes up inputs for a day that look like the real ones, so tests and benchmarks don't need the
+/// real inputs, which aren't meant to be shared
+///
+/// # Examples
+///
+/// ```
+/// use aoc_core::Generator;
+///
+/// fn generate(seed: u64, size: usize) -> String {
+///     (0..size as u64).map(|i| format!("{}\n", seed * i)).collect()
+/// }
+///
+/// let generator = Generator { size: 3, generate };
+/// assert_eq!(generator.input(2), "0\n2\n4\n");
+/// assert_eq!((generator.generate)(5, 2), "0\n5\n");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Generator {
+    /// How big the real inputs are, counted in whatever `generate` counts, such as lines or races
+    pub size: usize,
+    /// Makes up an input of the given size, which is always the same for the same seed
+    pub generate: fn(u64, usize) -> String,
+}
+
+impl Generator {
+    /// Makes up an input as big as the real ones
+    pub fn input(&self, seed: u64) -> String {
+        (self.generate)(seed, self.size)
+    }
+}
+
+/// Parses a [`Solution`] behind a [`DynSolution`], usable as a plain `fn` pointer
+///
+/// # Examples
+///
+/// ```
+/// # use aoc_core::{Part, Solution};
+/// # struct Lines(usize);
+/// # impl Solution for Lines {
+/// #     type PartOne = usize;
+/// #     type PartTwo = String;
+/// #     type Error = std::convert::Infallible;
+/// #     fn parse(input_text: &str) -> Result<Self, Self::Error> { Ok(Self(input_text.lines().count())) }
+/// #     fn part_one(&self) -> usize { self.0 }
+/// #     fn part_two(&self) -> String { format!("{} lines", self.0) }
+/// # }
+/// use aoc_core::{parse_dyn, DynError, DynSolution};
+///
+/// let parse: fn(&str) -> Result<Box<dyn DynSolution>, DynError> = parse_dyn::<Lines>;
+/// let solution = parse("a\nb").unwrap();
+/// assert_eq!(solution.answer(Part::One), "2");
+/// assert_eq!(solution.answer(Part::Two), "2 lines");
+/// ```
+pub fn parse_dyn<S: Solution + Send + Sync + 'static>(
+    input_text: &str,
+) -> Result<Box<dyn DynSolution>, DynError> {
+    match S::parse(input_text) {
+        Ok(solution) => Ok(Box::new(solution)),
+        Err(e) => Err(Box::new(e)),
+    }
+}
@@ -0,0 +1,173 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// Where a sequence of states starts repeating
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Period {
+    /// How many steps it takes to reach the first state that comes round again
+    pub prefix: usize,
+    /// How many steps it takes to come back round to the same state
+    pub length: usize,
+}
+
+impl Period {
+    /// The fewest steps that reach the same state as `steps` does
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_cycle::Period;
+    /// let period = Period { prefix: 3, length: 4 };
+    /// assert_eq!(period.reduce(2), 2);
+    /// assert_eq!(period.reduce(7), 3);
+    /// assert_eq!(period.reduce(1_000_000_000), 4);
+    /// ```
+    pub fn reduce(&self, steps: u64) -> u64 {
+        let (prefix, length) = (self.prefix as u64, self.length as u64);
+        match steps < prefix {
+            true => steps,
+            false => prefix + (steps - prefix) % length,
+        }
+    }
+}
+
+/// Finds the period with Floyd's tortoise and hare, which only ever holds a few states at once
+///
+/// # Examples
+///
+/// ```
+/// use aoc_cycle::{floyd, Period};
+/// // 1, 2, 4, 8, 16, 11, 1, 2, ... then round again
+/// let period = floyd(1u32, |n| n * 2 % 21);
+/// assert_eq!(period, Period { prefix: 0, length: 6 });
+/// ```
+pub fn floyd<S: Clone + Eq>(start: S, mut step: impl FnMut(&S) -> S) -> Period {
+    // The hare goes twice as fast, so they meet a whole number of cycles apart
+    let mut tortoise = step(&start);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        hare = step(&hare);
+    }
+
+    // Which means stepping from the start and from where they met, they meet where it starts
+    let mut prefix = 0;
+    tortoise = start;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    let mut length = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        length += 1;
+    }
+    Period { prefix, length }
+}
+
+/// Finds the period with Brent's algorithm, which holds as few states as [`floyd`] but usually
+/// takes fewer steps
+///
+/// # Examples
+///
+/// ```
+/// use aoc_cycle::{brent, Period};
+/// // 0, 1, 3, 7, 15, 31, 63, 27, 55, 11, 23, 47, 95, 91, 83, 67, 35, 71, 43, 87, 75, 51, 3, ...
+/// let period = brent(0u32, |n| (n * 2 + 1) % 100);
+/// assert_eq!(period, Period { prefix: 2, length: 20 });
+/// ```
+pub fn brent<S: Clone + Eq>(start: S, mut step: impl FnMut(&S) -> S) -> Period {
+    // The tortoise waits at each power of two for the hare to catch it up
+    let (mut power, mut length) = (1, 1);
+    let mut tortoise = start.clone();
+    let mut hare = step(&start);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // With the hare a whole cycle ahead, they meet where it starts
+    let mut tortoise = start.clone();
+    let mut hare = start;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    let mut prefix = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+    Period { prefix, length }
+}
+
+/// Finds the period by remembering every state until one comes round again. It holds every state
+/// in the prefix and cycle, but steps through each of them only once, so it's the one to use when
+/// stepping is slow.
+pub fn find_period<S: Clone + Eq + Hash>(start: S, mut step: impl FnMut(&S) -> S) -> Period {
+    let mut seen = HashMap::new();
+    let mut state = start;
+    let mut steps = 0;
+    loop {
+        if let Some(&first) = seen.get(&state) {
+            return Period {
+                prefix: first,
+                length: steps - first,
+            };
+        }
+        let next = step(&state);
+        seen.insert(state, steps);
+        state = next;
+        steps += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_agree() {
+        for modulus in 1..60u64 {
+            for multiplier in 0..10 {
+                let step = |n: &u64| (n * multiplier + 7) % modulus;
+                let period = find_period(3 % modulus, step);
+                assert_eq!(floyd(3 % modulus, step), period);
+                assert_eq!(brent(3 % modulus, step), period);
+
+                // The state after any number of steps is the same as after the reduced number
+                let walk = |steps: u64| (0..steps).fold(3 % modulus, |n, _| step(&n));
+                for steps in [0, 1, 5, 17, 100] {
+                    assert_eq!(walk(period.reduce(steps)), walk(steps));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_fixed_point() {
+        let period = brent("done".to_string(), |state| state.clone());
+        assert_eq!(
+            period,
+            Period {
+                prefix: 0,
+                length: 1
+            }
+        );
+        assert_eq!(
+            floyd(5, |n: &i32| (n - 1).max(0)),
+            Period {
+                prefix: 5,
+                length: 1
+            }
+        );
+    }
+}
@@ -0,0 +1,11 @@
+//! Finds where a state machine that's stepped over and over starts repeating, for puzzles that
+//! ask about a step far beyond what could be simulated, or about when several of them line up.
+//!
+//! Every state machine here has to have a finite number of states, or it might never repeat and
+//! these never return.
+
+mod detect;
+mod schedule;
+
+pub use detect::{brent, find_period, floyd, Period};
+pub use schedule::{find_goals, Schedule};
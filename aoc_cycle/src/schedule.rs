@@ -0,0 +1,211 @@
+use crate::brent;
+
+/// The steps at which something happens, when it eventually happens on a repeating schedule: at
+/// each step in `before`, and then at `start + offset + k * period` for each offset and every
+/// `k` from 0 up. Everything in `before` comes before `start`, and every offset is below `period`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Schedule {
+    pub before: Vec<u64>,
+    pub start: u64,
+    pub period: u64,
+    pub offsets: Vec<u64>,
+}
+
+impl Schedule {
+    /// Whether it happens at step `t`
+    pub fn contains(&self, t: u64) -> bool {
+        match t < self.start {
+            true => self.before.binary_search(&t).is_ok(),
+            false => self
+                .offsets
+                .binary_search(&((t - self.start) % self.period))
+                .is_ok(),
+        }
+    }
+
+    /// The first step it happens at, or `None` if it never does
+    pub fn first(&self) -> Option<u64> {
+        self.before
+            .first()
+            .copied()
+            .or_else(|| self.offsets.first().map(|offset| self.start + offset))
+    }
+
+    /// The steps at which both happen
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_cycle::Schedule;
+    /// // Every 4 steps from 1, and every 6 steps from 3 after happening at 0
+    /// let a = Schedule { before: vec![], start: 1, period: 4, offsets: vec![0] };
+    /// let b = Schedule { before: vec![0], start: 3, period: 6, offsets: vec![0] };
+    /// let both = a.intersection(&b);
+    /// assert_eq!(both.first(), Some(9));
+    /// assert!(both.contains(21) && both.contains(33) && !both.contains(15));
+    /// ```
+    pub fn intersection(&self, other: &Self) -> Self {
+        // Anything before the later start is in the `before` of whichever starts later
+        let mut before: Vec<u64> = self
+            .before
+            .iter()
+            .filter(|&&t| other.contains(t))
+            .chain(other.before.iter().filter(|&&t| self.contains(t)))
+            .copied()
+            .collect();
+        before.sort_unstable();
+        before.dedup();
+
+        let start = self.start.max(other.start);
+        let period = lcm(self.period, other.period);
+        let mut offsets: Vec<u64> = self
+            .offsets
+            .iter()
+            .flat_map(|a| {
+                other.offsets.iter().filter_map(move |b| {
+                    let (a, b) = (self.start + a, other.start + b);
+                    crt(a, self.period, b, other.period)
+                })
+            })
+            .map(|t| (t + period - start % period) % period)
+            .collect();
+        offsets.sort_unstable();
+        offsets.dedup();
+
+        Self {
+            before,
+            start,
+            period,
+            offsets,
+        }
+    }
+}
+
+/// Steps from `start` until it's repeating, and finds every step at which it's in a goal state
+///
+/// # Examples
+///
+/// ```
+/// use aoc_cycle::{find_goals, Schedule};
+/// // 0, 1, 2, 3, 4, 5, then 2, 3, 4, 5, ... and so on
+/// let goals = find_goals(0u32, |&n| if n == 5 { 2 } else { n + 1 }, |&n| n % 2 == 1);
+/// assert_eq!(goals, Schedule { before: vec![1], start: 2, period: 4, offsets: vec![1, 3] });
+/// assert_eq!(goals.first(), Some(1));
+/// assert!(goals.contains(1_000_001) && !goals.contains(1_000_000));
+/// ```
+pub fn find_goals<S: Clone + Eq>(
+    start: S,
+    mut step: impl FnMut(&S) -> S,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Schedule {
+    let cycle = brent(start.clone(), &mut step);
+    let (prefix, length) = (cycle.prefix as u64, cycle.length as u64);
+    let mut before = Vec::new();
+    let mut offsets = Vec::new();
+    let mut state = start;
+    for t in 0..prefix + length {
+        if is_goal(&state) {
+            match t < prefix {
+                true => before.push(t),
+                false => offsets.push(t - prefix),
+            }
+        }
+        state = step(&state);
+    }
+    Schedule {
+        before,
+        start: prefix,
+        period: length,
+        offsets,
+    }
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    match b {
+        0 => a,
+        _ => gcd(b, a % b),
+    }
+}
+
+fn lcm(a: u64, b: u64) -> u64 {
+    a / gcd(a, b) * b
+}
+
+/// The `(gcd, x, y)` where `a * x + b * y = gcd`
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    match b {
+        0 => (a, 1, 0),
+        _ => {
+            let (g, x, y) = extended_gcd(b, a % b);
+            (g, y, x - a / b * y)
+        }
+    }
+}
+
+/// The `t` below `lcm(m, n)` where `t % m == a % m` and `t % n == b % n`, if there is one
+fn crt(a: u64, m: u64, b: u64, n: u64) -> Option<u64> {
+    let (a, m, b, n) = (a as i128, m as i128, b as i128, n as i128);
+    let (g, x, _) = extended_gcd(m, n);
+    if (b - a) % g != 0 {
+        return None;
+    }
+    let lcm = m / g * n;
+    let k = ((b - a) / g * x).rem_euclid(n / g);
+    Some((a + m * k).rem_euclid(lcm) as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(2, 3, 3, 5), Some(8));
+        assert_eq!(crt(1, 4, 3, 6), Some(9));
+        assert_eq!(crt(0, 4, 1, 6), None);
+        assert_eq!(crt(7, 1, 3, 5), Some(3));
+    }
+
+    #[test]
+    fn test_intersection() {
+        // Odd states in a 7 step loop after 3 steps, and states that are 0 mod 3 in a 5 step loop
+        let a = find_goals(0u32, |&n| if n == 9 { 3 } else { n + 1 }, |&n| n % 2 == 1);
+        let b = find_goals(0u32, |&n| if n == 4 { 0 } else { n + 1 }, |&n| n % 3 == 0);
+        let both = a.intersection(&b);
+
+        let walk = |next: &dyn Fn(u32) -> u32, goal: &dyn Fn(u32) -> bool| {
+            (0..500)
+                .scan(0, |n, _| {
+                    let goal = goal(*n);
+                    *n = next(*n);
+                    Some(goal)
+                })
+                .collect::<Vec<_>>()
+        };
+        let in_a = walk(&|n| if n == 9 { 3 } else { n + 1 }, &|n| n % 2 == 1);
+        let in_b = walk(&|n| if n == 4 { 0 } else { n + 1 }, &|n| n % 3 == 0);
+        for t in 0..500 {
+            assert_eq!(a.contains(t), in_a[t as usize], "{}", t);
+            assert_eq!(b.contains(t), in_b[t as usize], "{}", t);
+            assert_eq!(
+                both.contains(t),
+                in_a[t as usize] && in_b[t as usize],
+                "{}",
+                t
+            );
+        }
+        assert_eq!(
+            both.first(),
+            (0..500).find(|&t| in_a[t] && in_b[t]).map(|t| t as u64)
+        );
+    }
+
+    #[test]
+    fn test_never() {
+        let never = find_goals(0u32, |&n| (n + 2) % 10, |&n| n % 2 == 1);
+        assert_eq!(never.first(), None);
+        let always = find_goals(0u32, |&n| (n + 1) % 3, |_| true);
+        assert_eq!(never.intersection(&always).first(), None);
+        assert_eq!(always.first(), Some(0));
+    }
+}
@@ -0,0 +1,105 @@
+use crate::Vec2;
+use std::fmt::Display;
+
+/// One of the four ways to move in a grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Every direction, clockwise from up
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// A quarter turn clockwise
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_geometry::Direction;
+    /// assert_eq!(Direction::Up.turn_right(), Direction::Right);
+    /// assert_eq!(Direction::Left.turn_right(), Direction::Up);
+    /// ```
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    /// A quarter turn counter clockwise
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_geometry::Direction;
+    /// assert_eq!(Direction::Up.turn_left(), Direction::Left);
+    /// ```
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    /// The direction facing the other way
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    /// A single step in the direction, where up is towards smaller `y`
+    pub fn delta(self) -> Vec2 {
+        match self {
+            Direction::Up => Vec2::new(0, -1),
+            Direction::Right => Vec2::new(1, 0),
+            Direction::Down => Vec2::new(0, 1),
+            Direction::Left => Vec2::new(-1, 0),
+        }
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction::Up | Direction::Down)
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        !self.is_vertical()
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Direction::Up => "up",
+            Direction::Right => "right",
+            Direction::Down => "down",
+            Direction::Left => "left",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_right().turn_left(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.opposite());
+            assert_eq!(direction.opposite().opposite(), direction);
+            assert_eq!(direction.opposite().delta(), -direction.delta());
+            assert_eq!(
+                direction.turn_right().delta(),
+                direction.delta().rotate_right()
+            );
+            assert_eq!(
+                direction.turn_left().delta(),
+                direction.delta().rotate_left()
+            );
+            assert_ne!(direction.is_vertical(), direction.is_horizontal());
+        }
+    }
+}
@@ -0,0 +1,14 @@
+//! 2D geometry shared between the days. Like `aoc_grid`, `x` is the column and `y` is the row,
+//! so `y` grows downwards and [`Direction::Up`] is towards smaller `y`.
+
+mod direction;
+mod line;
+mod polygon;
+mod rect;
+mod vec2;
+
+pub use direction::Direction;
+pub use line::Line;
+pub use polygon::Polygon;
+pub use rect::Rect;
+pub use vec2::{Point, Vec2};
@@ -0,0 +1,86 @@
+use crate::{Rect, Vec2};
+
+/// A straight line between two positions, including both ends
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Line {
+    pub start: Vec2,
+    pub end: Vec2,
+}
+
+impl Line {
+    pub fn new(start: Vec2, end: Vec2) -> Self {
+        Self { start, end }
+    }
+
+    /// The offset from the start to the end
+    pub fn delta(&self) -> Vec2 {
+        self.end - self.start
+    }
+
+    pub fn is_horizontal(&self) -> bool {
+        self.start.y == self.end.y
+    }
+
+    pub fn is_vertical(&self) -> bool {
+        self.start.x == self.end.x
+    }
+
+    /// Whether the line is at 45 degrees
+    pub fn is_diagonal(&self) -> bool {
+        let delta = self.delta();
+        delta.x.abs() == delta.y.abs() && delta != Vec2::ZERO
+    }
+
+    /// The number of steps from the start to the end, counting diagonal steps as one
+    pub fn steps(&self) -> i64 {
+        let delta = self.delta();
+        delta.x.abs().max(delta.y.abs())
+    }
+
+    pub fn bounds(&self) -> Rect {
+        Rect::new(self.start, self.end)
+    }
+
+    /// Every position on the line from the start to the end. Only lines that are horizontal,
+    /// vertical or diagonal pass through whole positions, so any other line is an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_geometry::{Line, Vec2};
+    /// let line = Line::new(Vec2::new(3, 4), Vec2::new(1, 4));
+    /// assert_eq!(
+    ///     line.points().collect::<Vec<_>>(),
+    ///     vec![Vec2::new(3, 4), Vec2::new(2, 4), Vec2::new(1, 4)]
+    /// );
+    /// ```
+    pub fn points(&self) -> impl Iterator<Item = Vec2> {
+        debug_assert!(
+            self.is_horizontal() || self.is_vertical() || self.is_diagonal(),
+            "{} to {} doesn't pass through whole positions",
+            self.start,
+            self.end
+        );
+        let Self { start, .. } = *self;
+        let step = self.delta().signum();
+        (0..=self.steps()).map(move |i| start + step * i)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line() {
+        let line = Line::new(Vec2::new(1, 1), Vec2::new(3, 3));
+        assert!(line.is_diagonal() && !line.is_horizontal() && !line.is_vertical());
+        assert_eq!(line.steps(), 2);
+        assert_eq!(line.points().last(), Some(line.end));
+        assert_eq!(line.bounds().area(), 9);
+
+        let point = Line::new(Vec2::new(5, 2), Vec2::new(5, 2));
+        assert!(point.is_horizontal() && point.is_vertical() && !point.is_diagonal());
+        assert_eq!(point.points().collect::<Vec<_>>(), vec![point.start]);
+    }
+}
@@ -0,0 +1,90 @@
+use crate::Vec2;
+
+/// A closed shape through each of its corners in turn, back to the first
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Polygon {
+    pub vertices: Vec<Vec2>,
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<Vec2>) -> Self {
+        Self { vertices }
+    }
+
+    /// Each edge, from a corner to the next
+    pub fn edges(&self) -> impl Iterator<Item = (Vec2, Vec2)> + '_ {
+        let next = self.vertices.iter().cycle().skip(1);
+        self.vertices.iter().copied().zip(next.copied())
+    }
+
+    /// Twice the area inside the edges, found with the shoelace formula. It's doubled so it's
+    /// always a whole number, whichever way round the corners go.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_geometry::{Polygon, Vec2};
+    /// let triangle = Polygon::new(vec![Vec2::new(0, 0), Vec2::new(3, 0), Vec2::new(0, 3)]);
+    /// assert_eq!(triangle.double_area(), 9);
+    /// ```
+    pub fn double_area(&self) -> i64 {
+        self.edges()
+            .map(|(a, b)| a.x * b.y - b.x * a.y)
+            .sum::<i64>()
+            .abs()
+    }
+
+    /// The number of whole positions the edges pass through, corners included
+    pub fn boundary_points(&self) -> i64 {
+        self.edges()
+            .map(|(a, b)| gcd((b.x - a.x).abs(), (b.y - a.y).abs()))
+            .sum()
+    }
+
+    /// The number of whole positions strictly inside the edges, from Pick's theorem
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_geometry::{Polygon, Vec2};
+    /// // The loop of pipes from day 10's examples, which encloses a single tile
+    /// let square = Polygon::new(vec![Vec2::new(1, 1), Vec2::new(3, 1), Vec2::new(3, 3), Vec2::new(1, 3)]);
+    /// assert_eq!(square.boundary_points(), 8);
+    /// assert_eq!(square.interior_points(), 1);
+    /// ```
+    pub fn interior_points(&self) -> i64 {
+        (self.double_area() - self.boundary_points()) / 2 + 1
+    }
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    match b {
+        0 => a,
+        _ => gcd(b, a % b),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_area() {
+        // An L shape, drawn counter clockwise then clockwise
+        let mut l_shape = Polygon::new(vec![
+            Vec2::new(0, 0),
+            Vec2::new(0, 4),
+            Vec2::new(4, 4),
+            Vec2::new(4, 2),
+            Vec2::new(2, 2),
+            Vec2::new(2, 0),
+        ]);
+        assert_eq!(l_shape.double_area(), 24);
+        l_shape.vertices.reverse();
+        assert_eq!(l_shape.double_area(), 24);
+        assert_eq!(l_shape.boundary_points(), 16);
+        assert_eq!(l_shape.interior_points(), 5);
+
+        assert_eq!(Polygon::new(Vec::new()).double_area(), 0);
+    }
+}
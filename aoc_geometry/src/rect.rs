@@ -0,0 +1,109 @@
+use crate::Vec2;
+
+/// An upright rectangle of positions, including its edges
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rect {
+    /// The top left corner
+    pub min: Vec2,
+    /// The bottom right corner
+    pub max: Vec2,
+}
+
+impl Rect {
+    /// The rectangle between any two opposite corners
+    pub fn new(a: Vec2, b: Vec2) -> Self {
+        Self {
+            min: Vec2::new(a.x.min(b.x), a.y.min(b.y)),
+            max: Vec2::new(a.x.max(b.x), a.y.max(b.y)),
+        }
+    }
+
+    /// The smallest rectangle holding every point, or `None` if there aren't any
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_geometry::{Rect, Vec2};
+    /// let points = [Vec2::new(2, 5), Vec2::new(-1, 3), Vec2::new(4, 4)];
+    /// let bounds = Rect::bounding(points).unwrap();
+    /// assert_eq!(bounds, Rect::new(Vec2::new(-1, 3), Vec2::new(4, 5)));
+    /// assert_eq!(Rect::bounding([]), None);
+    /// ```
+    pub fn bounding(points: impl IntoIterator<Item = Vec2>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(Self::new(first, first), |bounds, point| Self {
+            min: Vec2::new(bounds.min.x.min(point.x), bounds.min.y.min(point.y)),
+            max: Vec2::new(bounds.max.x.max(point.x), bounds.max.y.max(point.y)),
+        }))
+    }
+
+    pub fn width(&self) -> i64 {
+        self.max.x - self.min.x + 1
+    }
+
+    pub fn height(&self) -> i64 {
+        self.max.y - self.min.y + 1
+    }
+
+    /// The number of positions in the rectangle
+    pub fn area(&self) -> i64 {
+        self.width() * self.height()
+    }
+
+    pub fn contains(&self, point: Vec2) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+
+    /// Grows the rectangle by `by` on every side, so `expand(1)` also holds every position next to it
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_geometry::{Rect, Vec2};
+    /// let number = Rect::new(Vec2::new(0, 0), Vec2::new(2, 0));
+    /// assert!(number.expand(1).contains(Vec2::new(3, 1)));
+    /// assert!(!number.expand(1).contains(Vec2::new(4, 1)));
+    /// ```
+    pub fn expand(&self, by: i64) -> Self {
+        Self {
+            min: self.min - Vec2::new(by, by),
+            max: self.max + Vec2::new(by, by),
+        }
+    }
+
+    /// The positions in both rectangles, or `None` if they don't overlap
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let min = Vec2::new(self.min.x.max(other.min.x), self.min.y.max(other.min.y));
+        let max = Vec2::new(self.max.x.min(other.max.x), self.max.y.min(other.max.y));
+        (min.x <= max.x && min.y <= max.y).then_some(Self { min, max })
+    }
+
+    /// Every position in the rectangle, row by row
+    pub fn points(&self) -> impl Iterator<Item = Vec2> {
+        let Self { min, max } = *self;
+        (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| Vec2::new(x, y)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rect() {
+        let rect = Rect::new(Vec2::new(3, 4), Vec2::new(1, 2));
+        assert_eq!(rect.min, Vec2::new(1, 2));
+        assert_eq!((rect.width(), rect.height(), rect.area()), (3, 3, 9));
+        assert_eq!(rect.points().count(), 9);
+        assert!(rect.points().all(|point| rect.contains(point)));
+        assert!(!rect.contains(Vec2::new(0, 2)));
+
+        let other = Rect::new(Vec2::new(3, 3), Vec2::new(8, 8));
+        assert_eq!(
+            rect.intersection(&other),
+            Some(Rect::new(Vec2::new(3, 3), Vec2::new(3, 4)))
+        );
+        assert_eq!(rect.intersection(&other.expand(-1)), None);
+    }
+}
@@ -0,0 +1,210 @@
+use crate::Direction;
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+/// A position or an offset, as `(x, y)`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vec2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+/// A position, for when that reads better than [`Vec2`]
+pub type Point = Vec2;
+
+impl Vec2 {
+    pub const ZERO: Self = Self::new(0, 0);
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// Converts a position in an `aoc_grid::Grid`
+    pub fn from_pos((x, y): (usize, usize)) -> Self {
+        Self::new(x as i64, y as i64)
+    }
+
+    /// Converts back to a position in an `aoc_grid::Grid`, or `None` if it's above or left of it
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_geometry::Vec2;
+    /// assert_eq!(Vec2::new(3, 1).to_pos(), Some((3, 1)));
+    /// assert_eq!(Vec2::new(3, -1).to_pos(), None);
+    /// ```
+    pub fn to_pos(self) -> Option<(usize, usize)> {
+        Some((self.x.try_into().ok()?, self.y.try_into().ok()?))
+    }
+
+    /// The number of steps between the two, moving only up, down, left or right
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_geometry::Vec2;
+    /// assert_eq!(Vec2::new(1, 6).manhattan_distance(Vec2::new(5, 11)), 9);
+    /// ```
+    pub fn manhattan_distance(self, other: Self) -> i64 {
+        (self - other).manhattan_length()
+    }
+
+    pub fn manhattan_length(self) -> i64 {
+        self.x.abs() + self.y.abs()
+    }
+
+    /// Each of `x` and `y` as -1, 0 or 1, so a single step towards the offset
+    pub fn signum(self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+
+    /// A quarter turn clockwise, as it's seen with `y` growing downwards
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_geometry::{Direction, Vec2};
+    /// assert_eq!(Vec2::new(0, -2).rotate_right(), Vec2::new(2, 0));
+    /// assert_eq!(Direction::Up.delta().rotate_left(), Direction::Left.delta());
+    /// ```
+    pub fn rotate_right(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    /// A quarter turn counter clockwise, as it's seen with `y` growing downwards
+    pub fn rotate_left(self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+
+    /// The position a single step away in the direction
+    pub fn step(self, direction: Direction) -> Self {
+        self + direction.delta()
+    }
+
+    /// The positions above, right, below and left of this one
+    pub fn neighbours4(self) -> [Self; 4] {
+        Direction::ALL.map(|direction| self.step(direction))
+    }
+
+    /// The positions surrounding this one, including diagonals, clockwise from the top left
+    pub fn neighbours8(self) -> [Self; 8] {
+        [
+            (-1, -1),
+            (0, -1),
+            (1, -1),
+            (1, 0),
+            (1, 1),
+            (0, 1),
+            (-1, 1),
+            (-1, 0),
+        ]
+        .map(|(x, y)| self + Self::new(x, y))
+    }
+}
+
+impl From<(i64, i64)> for Vec2 {
+    fn from((x, y): (i64, i64)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Vec2 {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Vec2 {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<i64> for Vec2 {
+    type Output = Self;
+
+    fn mul(self, scale: i64) -> Self {
+        Self::new(self.x * scale, self.y * scale)
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl Display for Vec2 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let mut a = Vec2::new(3, -2);
+        let b = Vec2::new(-1, 5);
+        assert_eq!(a + b, Vec2::new(2, 3));
+        assert_eq!(a - b, Vec2::new(4, -7));
+        assert_eq!(a * 3, Vec2::new(9, -6));
+        assert_eq!(-a, Vec2::new(-3, 2));
+        assert_eq!((a - b).signum(), Vec2::new(1, -1));
+
+        a += b;
+        assert_eq!(a, Vec2::new(2, 3));
+        a -= b * 2;
+        assert_eq!(a, Vec2::new(4, -7));
+        assert_eq!(a.to_string(), "(4, -7)");
+    }
+
+    #[test]
+    fn test_rotation() {
+        let a = Vec2::new(3, -2);
+        assert_eq!(a.rotate_right().rotate_right(), -a);
+        assert_eq!(a.rotate_right().rotate_left(), a);
+        assert_eq!(a.rotate_left().rotate_left().rotate_left().rotate_left(), a);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let a = Vec2::new(1, 1);
+        assert_eq!(
+            a.neighbours4(),
+            [
+                Vec2::new(1, 0),
+                Vec2::new(2, 1),
+                Vec2::new(1, 2),
+                Vec2::new(0, 1)
+            ]
+        );
+        assert!(a
+            .neighbours8()
+            .iter()
+            .all(|neighbour| neighbour.manhattan_distance(a) <= 2 && *neighbour != a));
+        assert_eq!(Vec2::from_pos((4, 2)).to_pos(), Some((4, 2)));
+    }
+}
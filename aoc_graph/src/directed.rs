@@ -0,0 +1,359 @@
+use std::{collections::HashMap, fmt::Debug, fmt::Display, hash::Hash, ops::Add};
+
+/// A cycle found in a graph that needed not to have one, from a node back round to just before it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle<N> {
+    pub nodes: Vec<N>,
+}
+
+impl<N: Debug> Display for Cycle<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "The graph has a cycle through {:?}", self.nodes)
+    }
+}
+
+impl<N: Debug> std::error::Error for Cycle<N> {}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mark {
+    /// Still on the stack, so finding it again means there's a cycle
+    Visiting,
+    Done,
+}
+
+/// Orders every node reachable from `nodes` so each comes before all of its successors, or gives
+/// the first cycle found that makes that impossible
+///
+/// # Examples
+///
+/// ```
+/// use aoc_graph::{toposort, Cycle};
+///
+/// let after = |&n: &u8| match n {
+///     1 => vec![3, 2],
+///     2 => vec![3],
+///     _ => vec![],
+/// };
+/// assert_eq!(toposort([1], after), Ok(vec![1, 2, 3]));
+///
+/// let around = |&n: &u8| vec![(n + 1) % 3];
+/// assert_eq!(toposort([0], around), Err(Cycle { nodes: vec![0, 1, 2] }));
+/// ```
+pub fn toposort<N, I>(
+    nodes: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+) -> Result<Vec<N>, Cycle<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut marks = HashMap::new();
+    let mut order = Vec::new();
+    for root in nodes {
+        if marks.contains_key(&root) {
+            continue;
+        }
+        // Depth first, keeping the successors left to visit of each node on the path so far
+        marks.insert(root.clone(), Mark::Visiting);
+        let children = successors(&root).into_iter();
+        let mut stack = vec![(root, children)];
+        while let Some((_, children)) = stack.last_mut() {
+            let Some(child) = children.next() else {
+                let (node, _) = stack.pop().expect("The stack isn't empty");
+                marks.insert(node.clone(), Mark::Done);
+                order.push(node);
+                continue;
+            };
+            match marks.get(&child) {
+                None => {
+                    marks.insert(child.clone(), Mark::Visiting);
+                    let children = successors(&child).into_iter();
+                    stack.push((child, children));
+                }
+                Some(Mark::Visiting) => {
+                    let start = stack
+                        .iter()
+                        .position(|(node, _)| *node == child)
+                        .expect("Nodes being visited are on the stack");
+                    let nodes = stack.drain(start..).map(|(node, _)| node).collect();
+                    return Err(Cycle { nodes });
+                }
+                Some(Mark::Done) => {}
+            }
+        }
+    }
+    order.reverse();
+    Ok(order)
+}
+
+/// The cost of the most expensive path to every node that can be reached from `start`, where
+/// `successors` gives each successor alongside the cost of moving to it, or the first cycle found
+/// if there is one, since a cycle makes paths as long as you like. [`Default`] is taken as zero.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_graph::longest_paths;
+///
+/// // Going straight from 1 to 3 costs less than going round by 2
+/// let successors = |&n: &u8| match n {
+///     1 => vec![(2, 4), (3, 5)],
+///     2 => vec![(3, 2)],
+///     _ => vec![],
+/// };
+/// let costs = longest_paths(1, successors).unwrap();
+/// assert_eq!((costs[&1], costs[&2], costs[&3]), (0, 4, 6));
+///
+/// assert!(longest_paths(0u8, |&n| vec![((n + 1) % 3, 1)]).is_err());
+/// ```
+pub fn longest_paths<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+) -> Result<HashMap<N, C>, Cycle<N>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let order = toposort([start.clone()], |node| {
+        successors(node).into_iter().map(|(next, _)| next)
+    })?;
+
+    // Every path into a node comes before it in the order, so it's done by the time it's reached
+    let mut costs = HashMap::from([(start, C::default())]);
+    for node in order {
+        let cost = costs[&node];
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            costs
+                .entry(next)
+                .and_modify(|best| *best = next_cost.max(*best))
+                .or_insert(next_cost);
+        }
+    }
+    Ok(costs)
+}
+
+/// A cycle reachable from any of `nodes`, if there is one
+pub fn find_cycle<N, I>(
+    nodes: impl IntoIterator<Item = N>,
+    successors: impl FnMut(&N) -> I,
+) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    toposort(nodes, successors).err().map(|cycle| cycle.nodes)
+}
+
+/// Groups every node reachable from `nodes` into the largest groups where each node can reach
+/// every other, using Tarjan's algorithm. A group only comes after the groups it leads to.
+///
+/// # Examples
+///
+/// ```
+/// let successors = |&n: &u8| match n {
+///     1 => vec![2],
+///     2 => vec![1, 3],
+///     3 => vec![4],
+///     _ => vec![3],
+/// };
+/// let mut components = aoc_graph::strongly_connected_components([1], successors);
+/// components.iter_mut().for_each(|component| component.sort());
+/// assert_eq!(components, vec![vec![3, 4], vec![1, 2]]);
+/// ```
+pub fn strongly_connected_components<N, I>(
+    nodes: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+) -> Vec<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut tarjan = Tarjan {
+        indices: HashMap::new(),
+        reached: Vec::new(),
+        low_links: Vec::new(),
+        on_stack: Vec::new(),
+        stack: Vec::new(),
+    };
+    let mut components = Vec::new();
+    for root in nodes {
+        if tarjan.indices.contains_key(&root) {
+            continue;
+        }
+        // Depth first, keeping the successors left to visit of each node on the path so far
+        let children = successors(&root).into_iter();
+        let mut path = vec![(tarjan.reach(root), children)];
+        while let Some((index, children)) = path.last_mut() {
+            let index = *index;
+            if let Some(child) = children.next() {
+                match tarjan.indices.get(&child) {
+                    None => {
+                        let children = successors(&child).into_iter();
+                        path.push((tarjan.reach(child), children));
+                    }
+                    Some(&child) if tarjan.on_stack[child] => {
+                        tarjan.low_links[index] = tarjan.low_links[index].min(child);
+                    }
+                    Some(_) => {}
+                }
+                continue;
+            }
+
+            path.pop();
+            if let Some((parent, _)) = path.last() {
+                let parent = *parent;
+                tarjan.low_links[parent] = tarjan.low_links[parent].min(tarjan.low_links[index]);
+            }
+            if tarjan.low_links[index] == index {
+                components.push(tarjan.pop_component(index));
+            }
+        }
+    }
+    components
+}
+
+struct Tarjan<N> {
+    /// Each node is known by the order it was reached in
+    indices: HashMap<N, usize>,
+    reached: Vec<N>,
+    /// The earliest node each one is known to reach that's still on the stack
+    low_links: Vec<usize>,
+    on_stack: Vec<bool>,
+    stack: Vec<usize>,
+}
+
+impl<N: Clone + Eq + Hash> Tarjan<N> {
+    fn reach(&mut self, node: N) -> usize {
+        let index = self.reached.len();
+        self.indices.insert(node.clone(), index);
+        self.reached.push(node);
+        self.low_links.push(index);
+        self.on_stack.push(true);
+        self.stack.push(index);
+        index
+    }
+
+    /// Takes the node and everything reached after it off the stack, as a component
+    fn pop_component(&mut self, index: usize) -> Vec<N> {
+        let start = self
+            .stack
+            .iter()
+            .rposition(|&member| member == index)
+            .expect("The node is on the stack");
+        let members: Vec<usize> = self.stack.drain(start..).collect();
+        members
+            .into_iter()
+            .map(|member| {
+                self.on_stack[member] = false;
+                self.reached[member].clone()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toposort() {
+        // Clothes, which have to go on before the ones listed after them
+        let after = |&item: &&str| match item {
+            "socks" => vec!["shoes"],
+            "trousers" => vec!["shoes", "belt"],
+            "shirt" => vec!["belt", "tie"],
+            "tie" => vec!["jacket"],
+            "belt" => vec!["jacket"],
+            _ => vec![],
+        };
+        let items = [
+            "jacket", "shoes", "tie", "belt", "shirt", "trousers", "socks",
+        ];
+        let order = toposort(items, after).unwrap();
+        assert_eq!(order.len(), items.len());
+        for item in items {
+            let position = |item| order.iter().position(|other| *other == item).unwrap();
+            assert!(after(&item)
+                .into_iter()
+                .all(|later| position(item) < position(later)));
+        }
+        assert_eq!(find_cycle(items, after), None);
+    }
+
+    #[test]
+    fn test_cycles() {
+        let successors = |&n: &u8| match n {
+            0 => vec![1],
+            1 => vec![2],
+            2 => vec![3, 1],
+            _ => vec![],
+        };
+        assert_eq!(find_cycle([0], successors), Some(vec![1, 2]));
+        assert_eq!(find_cycle([3], successors), None);
+
+        let looped = |&n: &u8| vec![n];
+        let cycle = toposort([5], looped).unwrap_err();
+        assert_eq!(cycle.nodes, vec![5]);
+        assert_eq!(cycle.to_string(), "The graph has a cycle through [5]");
+    }
+
+    #[test]
+    fn test_longest_paths() {
+        // Tasks with how long they take, where each has to wait for the ones before it
+        let durations = [
+            ("dig", 3),
+            ("pour", 2),
+            ("frame", 4),
+            ("wire", 1),
+            ("roof", 2),
+        ];
+        let after = |&task: &&str| match task {
+            "start" => vec!["dig", "frame"],
+            "dig" => vec!["pour"],
+            "pour" => vec!["wire", "roof"],
+            "frame" => vec!["roof"],
+            _ => vec![],
+        };
+        let successors = |task: &&str| {
+            after(task)
+                .into_iter()
+                .map(|next| (next, durations.iter().find(|(t, _)| *t == next).unwrap().1))
+                .collect::<Vec<_>>()
+        };
+        let finished = longest_paths("start", successors).unwrap();
+        assert_eq!(finished.len(), 6);
+        assert_eq!(finished["pour"], 5);
+        assert_eq!(finished["roof"], 7);
+        assert_eq!(finished["wire"], 6);
+
+        let looped = |&n: &u8| vec![(n, 1)];
+        assert_eq!(longest_paths(5, looped).unwrap_err().nodes, vec![5]);
+    }
+
+    #[test]
+    fn test_components() {
+        let successors = |&n: &u8| match n {
+            0 => vec![1],
+            1 => vec![2, 4],
+            2 => vec![0, 3],
+            3 => vec![],
+            4 => vec![5],
+            5 => vec![6],
+            6 => vec![4, 7],
+            _ => vec![],
+        };
+        let mut components = strongly_connected_components(0..8, successors);
+        components.iter_mut().for_each(|component| component.sort());
+        assert_eq!(components.len(), 4);
+        for expected in [vec![0, 1, 2], vec![3], vec![4, 5, 6], vec![7]] {
+            assert!(components.contains(&expected));
+        }
+
+        // Groups come after the ones they lead to
+        let position = |n| components.iter().position(|c| c.contains(&n)).unwrap();
+        assert!(position(3) < position(0) && position(4) < position(0));
+        assert!(position(7) < position(4));
+    }
+}
@@ -0,0 +1,10 @@
+//! Graph searches shared between the days. None of them need the graph up front, just a starting
+//! node and a function giving the neighbours of any node, so a day only has to write its state and
+//! how it moves from one state to the next. Nodes can be anything `Clone + Eq + Hash`, e.g. a
+//! position alongside the direction it's facing.
+
+mod directed;
+mod search;
+
+pub use directed::{find_cycle, longest_paths, strongly_connected_components, toposort, Cycle};
+pub use search::{astar, bfs, bfs_distances, dijkstra, dijkstra_all};
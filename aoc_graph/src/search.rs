@@ -0,0 +1,308 @@
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// The shortest path from `start` to the first node that's a goal, counting every step as one, or
+/// `None` if there's no reaching one. The path includes both ends.
+///
+/// # Examples
+///
+/// ```
+/// // Doubling or adding one, the quickest way from 1 to 10 is 1, 2, 4, 5, 10
+/// let path = aoc_graph::bfs(1, |&n| [n * 2, n + 1], |&n| n == 10).unwrap();
+/// assert_eq!(path, vec![1, 2, 4, 5, 10]);
+/// ```
+pub fn bfs<N, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut parents: HashMap<N, Option<N>> = HashMap::from([(start.clone(), None)]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            let mut path = vec![node];
+            while let Some(Some(parent)) = parents.get(&path[path.len() - 1]) {
+                path.push(parent.clone());
+            }
+            path.reverse();
+            return Some(path);
+        }
+        for next in neighbours(&node) {
+            if let Entry::Vacant(entry) = parents.entry(next.clone()) {
+                entry.insert(Some(node.clone()));
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+/// The number of steps to every node that can be reached from `start`, including `start` itself
+///
+/// # Examples
+///
+/// ```
+/// let distances = aoc_graph::bfs_distances(0, |&n: &i32| [(n + 1).min(5), (n - 1).max(-5)]);
+/// assert_eq!(distances.len(), 11);
+/// assert_eq!(distances[&-3], 3);
+/// ```
+pub fn bfs_distances<N, I>(start: N, mut neighbours: impl FnMut(&N) -> I) -> HashMap<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((node, distance)) = queue.pop_front() {
+        for next in neighbours(&node) {
+            if let Entry::Vacant(entry) = distances.entry(next.clone()) {
+                entry.insert(distance + 1);
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+    distances
+}
+
+/// The cheapest path from `start` to the first node that's a goal, along with its cost, where
+/// `neighbours` gives each neighbour alongside the cost of moving to it. Costs can't be negative,
+/// and [`Default`] is taken as zero.
+///
+/// # Examples
+///
+/// ```
+/// let costs = [[1, 9, 1], [1, 9, 1], [1, 1, 1]];
+/// let neighbours = |&(x, y): &(usize, usize)| {
+///     [(x + 1, y), (x, y + 1)]
+///         .into_iter()
+///         .filter(|&(x, y)| x < 3 && y < 3)
+///         .map(|(x, y)| ((x, y), costs[y][x]))
+///         .collect::<Vec<_>>()
+/// };
+/// let (path, cost) = aoc_graph::dijkstra((0, 0), neighbours, |&pos| pos == (2, 2)).unwrap();
+/// assert_eq!(cost, 4);
+/// assert_eq!(path, vec![(0, 0), (0, 1), (0, 2), (1, 2), (2, 2)]);
+/// ```
+pub fn dijkstra<N, C, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, neighbours, |_| C::default(), is_goal)
+}
+
+/// The cost of the cheapest path to every node that can be reached from `start`
+pub fn dijkstra_all<N, C, I>(start: N, neighbours: impl FnMut(&N) -> I) -> HashMap<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let (explored, _) = best_first(start, neighbours, |_| C::default(), |_| false);
+    explored.nodes.into_iter().zip(explored.costs).collect()
+}
+
+/// Like [`dijkstra`], but searching towards the goal first using `heuristic`, which estimates the
+/// cost from a node to the nearest goal. The path is only sure to be the cheapest when the
+/// estimate is never more than the real cost, e.g. the Manhattan distance when each step costs at
+/// least one.
+///
+/// # Examples
+///
+/// ```
+/// let goal = (4i32, 3i32);
+/// let neighbours = |&(x, y): &(i32, i32)| [((x + 1, y), 1), ((x, y + 1), 1), ((x - 1, y), 1)];
+/// let heuristic = |&(x, y): &(i32, i32)| (goal.0 - x).abs() + (goal.1 - y).abs();
+/// let (path, cost) = aoc_graph::astar((0, 0), neighbours, heuristic, |&pos| pos == goal).unwrap();
+/// assert_eq!(cost, 7);
+/// assert_eq!(path.len(), 8);
+/// ```
+pub fn astar<N, C, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    heuristic: impl FnMut(&N) -> C,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let (explored, goal) = best_first(start, neighbours, heuristic, is_goal);
+    let goal = goal?;
+    let mut path = vec![goal];
+    while let Some(parent) = explored.parents[path[path.len() - 1]] {
+        path.push(parent);
+    }
+    let nodes = path
+        .into_iter()
+        .rev()
+        .map(|i| explored.nodes[i].clone())
+        .collect();
+    Some((nodes, explored.costs[goal]))
+}
+
+/// Every node a search has come across, by the order it came across them, so the heap only has to
+/// hold indices rather than needing the nodes to be `Ord`
+struct Explored<N, C> {
+    nodes: Vec<N>,
+    indices: HashMap<N, usize>,
+    /// The cheapest cost found so far to each node
+    costs: Vec<C>,
+    parents: Vec<Option<usize>>,
+}
+
+/// Explores the cheapest nodes first, as estimated by their cost so far plus the heuristic,
+/// stopping at the first goal it takes from the heap
+fn best_first<N, C, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> (Explored<N, C>, Option<usize>)
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let zero = C::default();
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), zero, 0))]);
+    let mut explored = Explored {
+        indices: HashMap::from([(start.clone(), 0)]),
+        nodes: vec![start],
+        costs: vec![zero],
+        parents: vec![None],
+    };
+
+    while let Some(Reverse((_, cost, i))) = heap.pop() {
+        // A cheaper way here was already found after this one was queued
+        if cost > explored.costs[i] {
+            continue;
+        }
+        if is_goal(&explored.nodes[i]) {
+            return (explored, Some(i));
+        }
+        for (next, step) in neighbours(&explored.nodes[i]) {
+            let next_cost = cost + step;
+            let j = match explored.indices.get(&next) {
+                Some(&j) if next_cost >= explored.costs[j] => continue,
+                Some(&j) => {
+                    explored.costs[j] = next_cost;
+                    explored.parents[j] = Some(i);
+                    j
+                }
+                None => {
+                    let j = explored.nodes.len();
+                    explored.indices.insert(next.clone(), j);
+                    explored.nodes.push(next.clone());
+                    explored.costs.push(next_cost);
+                    explored.parents.push(Some(i));
+                    j
+                }
+            };
+            heap.push(Reverse((next_cost + heuristic(&next), next_cost, j)));
+        }
+    }
+    (explored, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Pos = (usize, usize);
+
+    /// A maze where `#` is a wall, as a start, goal and neighbour function over `(x, y)`
+    fn maze(text: &'static str) -> (Pos, Pos, impl Fn(&Pos) -> Vec<Pos>) {
+        let rows: Vec<&[u8]> = text.lines().map(str::as_bytes).collect();
+        let find = |c: u8| {
+            rows.iter()
+                .enumerate()
+                .find_map(|(y, row)| Some((row.iter().position(|&cell| cell == c)?, y)))
+                .unwrap()
+        };
+        let (start, goal) = (find(b'S'), find(b'G'));
+        let neighbours = move |&(x, y): &Pos| {
+            [
+                (x + 1, y),
+                (x, y + 1),
+                (x.wrapping_sub(1), y),
+                (x, y.wrapping_sub(1)),
+            ]
+            .into_iter()
+            .filter(|&(x, y)| {
+                rows.get(y)
+                    .and_then(|row| row.get(x))
+                    .is_some_and(|&c| c != b'#')
+            })
+            .collect()
+        };
+        (start, goal, neighbours)
+    }
+
+    const MAZE: &str = "S..#....
+.#.#.##.
+.#...#G.
+.####.#.
+......#.";
+
+    #[test]
+    fn test_searches_agree() {
+        let (start, goal, neighbours) = maze(MAZE);
+        let path = bfs(start, &neighbours, |&pos| pos == goal).unwrap();
+        assert_eq!(path.first(), Some(&start));
+        assert_eq!(path.last(), Some(&goal));
+        assert!(path
+            .windows(2)
+            .all(|pair| neighbours(&pair[0]).contains(&pair[1])));
+
+        let steps = path.len() - 1;
+        assert_eq!(bfs_distances(start, &neighbours)[&goal], steps);
+
+        let weighted = |pos: &Pos| neighbours(pos).into_iter().map(|next| (next, 1));
+        let (_, cost) = dijkstra(start, weighted, |&pos| pos == goal).unwrap();
+        assert_eq!(cost, steps);
+        assert_eq!(dijkstra_all(start, weighted)[&goal], steps);
+
+        let manhattan = |&(x, y): &Pos| x.abs_diff(goal.0) + y.abs_diff(goal.1);
+        let (path, cost) = astar(start, weighted, manhattan, |&pos| pos == goal).unwrap();
+        assert_eq!((path.len() - 1, cost), (steps, steps));
+    }
+
+    #[test]
+    fn test_unreachable() {
+        let (start, _, neighbours) = maze("S.#G");
+        assert_eq!(bfs(start, &neighbours, |&pos| pos == (3, 0)), None);
+        assert_eq!(bfs_distances(start, &neighbours).len(), 2);
+        let weighted = |pos: &Pos| neighbours(pos).into_iter().map(|next| (next, 1u32));
+        assert_eq!(dijkstra(start, weighted, |&pos| pos == (3, 0)), None);
+    }
+
+    #[test]
+    fn test_cheaper_later() {
+        // The direct edge is found first, but going round is cheaper
+        let neighbours = |&node: &char| match node {
+            'a' => vec![('c', 10), ('b', 1)],
+            'b' => vec![('c', 2)],
+            _ => vec![],
+        };
+        assert_eq!(
+            dijkstra('a', neighbours, |&node| node == 'c'),
+            Some((vec!['a', 'b', 'c'], 3))
+        );
+        assert_eq!(dijkstra_all('a', neighbours)[&'c'], 3);
+    }
+}
@@ -0,0 +1,473 @@
+//! A 2D grid parsed straight from puzzle text. Positions are always `(x, y)`, with `x` being the column and `y` the row, starting from the top left.
+
+use std::{
+    collections::{HashSet, VecDeque},
+    fmt::Display,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+/// A position in the grid, as `(x, y)`
+pub type Pos = (usize, usize);
+
+const OFFSETS_4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+const OFFSETS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    /// A row didn't have the same number of cells as the first row
+    Ragged {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GridError::Ragged {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Row {} has {} cells, expected {} like the first row",
+                row, found, expected
+            ),
+        }
+    }
+}
+
+impl std::error::Error for GridError {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid filled with copies of `value`
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Creates a grid from its rows, which all need to be the same length
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_grid::{Grid, GridError};
+    /// let grid = Grid::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    /// assert_eq!(grid[(1, 0)], 2);
+    ///
+    /// let ragged = Grid::from_rows(vec![vec![1, 2], vec![3]]);
+    /// assert_eq!(ragged, Err(GridError::Ragged { row: 1, expected: 2, found: 1 }));
+    /// ```
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, GridError> {
+        let width = rows.first().map_or(0, |row| row.len());
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+        for (y, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                return Err(GridError::Ragged {
+                    row: y,
+                    expected: width,
+                    found: row.len(),
+                });
+            }
+            cells.extend(row);
+        }
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// Parses puzzle text, converting each character into a cell. Surrounding whitespace on each line and blank lines are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_grid::Grid;
+    /// let grid = Grid::parse_with("#.\n.#", |c| c == '#').unwrap();
+    /// assert_eq!(grid.get(0, 0), Some(&true));
+    /// assert_eq!(grid.get(1, 0), Some(&false));
+    /// assert_eq!(grid.get(2, 0), None);
+    /// ```
+    pub fn parse_with<F>(input_text: &str, f: F) -> Result<Self, GridError>
+    where
+        F: Fn(char) -> T,
+    {
+        let rows = input_text
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(|line| line.chars().map(&f).collect())
+            .collect();
+        Self::from_rows(rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_within_bounds(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        match self.is_within_bounds(x, y) {
+            true => Some(&self.cells[y * self.width + x]),
+            false => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        match self.is_within_bounds(x, y) {
+            true => Some(&mut self.cells[y * self.width + x]),
+            false => None,
+        }
+    }
+
+    /// Offsets the position, returning `None` if it would leave the grid
+    pub fn offset(&self, (x, y): Pos, (dx, dy): (isize, isize)) -> Option<Pos> {
+        let x = x.checked_add_signed(dx)?;
+        let y = y.checked_add_signed(dy)?;
+        match self.is_within_bounds(x, y) {
+            true => Some((x, y)),
+            false => None,
+        }
+    }
+
+    /// The positions above, right, below and left of `pos` that are within the grid
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_grid::Grid;
+    /// let grid = Grid::new(3, 3, '.');
+    /// assert_eq!(grid.neighbours4((0, 0)).collect::<Vec<_>>(), vec![(1, 0), (0, 1)]);
+    /// assert_eq!(grid.neighbours4((1, 1)).count(), 4);
+    /// ```
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        OFFSETS_4
+            .iter()
+            .filter_map(move |offset| self.offset(pos, *offset))
+    }
+
+    /// The positions surrounding `pos`, including diagonals, that are within the grid
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_grid::Grid;
+    /// let grid = Grid::new(3, 3, '.');
+    /// assert_eq!(grid.neighbours8((0, 0)).collect::<Vec<_>>(), vec![(1, 0), (1, 1), (0, 1)]);
+    /// assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+    /// ```
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        OFFSETS_8
+            .iter()
+            .filter_map(move |offset| self.offset(pos, *offset))
+    }
+
+    /// Every position in the grid, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| (i % width, i / width))
+    }
+
+    /// Every position in the grid alongside its cell, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on 0, but there's nothing to iterate anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Every position whose cell matches the predicate, row by row
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_grid::Grid;
+    /// let grid: Grid<char> = "#..\n..#".parse().unwrap();
+    /// assert_eq!(grid.find_all(|c| *c == '#').collect::<Vec<_>>(), vec![(0, 0), (2, 1)]);
+    /// ```
+    pub fn find_all<'a, P>(&'a self, predicate: P) -> impl Iterator<Item = Pos> + 'a
+    where
+        P: Fn(&T) -> bool + 'a,
+    {
+        self.iter()
+            .filter_map(move |(pos, cell)| predicate(cell).then_some(pos))
+    }
+
+    /// Every position connected to `start` through the 4 neighbours whose cells match the predicate. Empty if `start` doesn't match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_grid::Grid;
+    /// let grid: Grid<char> = "..#\n.##\n#..".parse().unwrap();
+    /// let filled = grid.flood_fill((0, 0), |c| *c == '.');
+    /// assert_eq!(filled.len(), 3);
+    /// assert!(!filled.contains(&(2, 2)));
+    /// ```
+    pub fn flood_fill<P>(&self, start: Pos, predicate: P) -> HashSet<Pos>
+    where
+        P: Fn(&T) -> bool,
+    {
+        let mut filled = HashSet::new();
+        if !self.get(start.0, start.1).is_some_and(&predicate) {
+            return filled;
+        }
+
+        let mut queue = VecDeque::from([start]);
+        filled.insert(start);
+        while let Some(pos) = queue.pop_front() {
+            for next in self.neighbours4(pos) {
+                if predicate(&self[next]) && filled.insert(next) {
+                    queue.push_back(next);
+                }
+            }
+        }
+        filled
+    }
+
+    /// Converts every cell, keeping the layout
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: Fn(&T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Swaps the rows and columns
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_grid::Grid;
+    /// let grid: Grid<char> = "abc\ndef".parse().unwrap();
+    /// assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+    /// ```
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.remap(self.height, self.width, |x, y| (y, x))
+    }
+
+    /// Rotates a quarter turn clockwise
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_grid::Grid;
+    /// let grid: Grid<char> = "abc\ndef".parse().unwrap();
+    /// assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+    /// ```
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.remap(self.height, self.width, |x, y| (y, height - 1 - x))
+    }
+
+    /// Rotates a quarter turn counter clockwise
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_grid::Grid;
+    /// let grid: Grid<char> = "abc\ndef".parse().unwrap();
+    /// assert_eq!(grid.rotate_counter_clockwise().to_string(), "cf\nbe\nad");
+    /// ```
+    pub fn rotate_counter_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.remap(self.height, self.width, |x, y| (width - 1 - y, x))
+    }
+
+    /// Builds a new grid of the given size, where `source` maps each new position to the position it's copied from
+    fn remap<F>(&self, width: usize, height: usize, source: F) -> Self
+    where
+        T: Clone,
+        F: Fn(usize, usize) -> Pos,
+    {
+        let cells = (0..width * height)
+            .map(|i| self[source(i % width, i / width)].clone())
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = GridError;
+
+    fn from_str(input_text: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(input_text, |c| c)
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    /// # Panics
+    /// If the position is outside of the grid
+    fn index(&self, (x, y): Pos) -> &Self::Output {
+        assert!(
+            self.is_within_bounds(x, y),
+            "({}, {}) is outside the grid",
+            x,
+            y
+        );
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, (x, y): Pos) -> &mut Self::Output {
+        assert!(
+            self.is_within_bounds(x, y),
+            "({}, {}) is outside the grid",
+            x,
+            y
+        );
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let input_text = "467..
+        ...*.
+        ..35.";
+
+        let grid: Grid<char> = input_text.parse().unwrap();
+        assert_eq!(grid.width(), 5);
+        assert_eq!(grid.height(), 3);
+        assert_eq!(grid[(3, 1)], '*');
+        assert_eq!(grid.get(5, 0), None);
+        assert_eq!(grid.get(0, 3), None);
+        assert_eq!(grid.row(2), &['.', '.', '3', '5', '.']);
+        assert_eq!(grid.to_string(), "467..\n...*.\n..35.");
+
+        let ragged = "467..\n...*";
+        assert_eq!(
+            ragged.parse::<Grid<char>>(),
+            Err(GridError::Ragged {
+                row: 1,
+                expected: 5,
+                found: 4
+            })
+        );
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid: Grid<char> = "abc\ndef".parse().unwrap();
+        let rows = grid
+            .rows()
+            .map(|r| r.iter().collect::<String>())
+            .collect::<Vec<_>>();
+        assert_eq!(rows, vec!["abc", "def"]);
+        let columns = grid
+            .columns()
+            .map(|c| c.collect::<String>())
+            .collect::<Vec<_>>();
+        assert_eq!(columns, vec!["ad", "be", "cf"]);
+    }
+
+    #[test]
+    fn test_rotation() {
+        let grid: Grid<char> = "abc\ndef".parse().unwrap();
+        let spun = grid
+            .rotate_clockwise()
+            .rotate_clockwise()
+            .rotate_clockwise()
+            .rotate_clockwise();
+        assert_eq!(spun, grid);
+        assert_eq!(grid.rotate_clockwise().rotate_counter_clockwise(), grid);
+        assert_eq!(grid.transpose().transpose(), grid);
+    }
+
+    #[test]
+    fn test_mutation() {
+        let mut grid = Grid::new(2, 2, 0);
+        grid[(1, 0)] = 5;
+        *grid.get_mut(0, 1).unwrap() = 7;
+        assert_eq!(grid.get_mut(2, 0), None);
+        assert_eq!(
+            grid.map(|n| n * 2).rows().collect::<Vec<_>>(),
+            vec![&[0, 10], &[14, 0]]
+        );
+    }
+}
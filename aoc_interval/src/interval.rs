@@ -0,0 +1,115 @@
+use crate::Bound;
+use std::fmt::Display;
+
+/// The numbers from `start` up to but not including `end`. It's empty when `end` isn't past
+/// `start`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Bound> Interval<T> {
+    pub fn new(start: T, end: T) -> Self {
+        Self { start, end }
+    }
+
+    /// The `len` numbers from `start`
+    pub fn with_len(start: T, len: T) -> Self {
+        Self::new(start, start + len)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    pub fn len(&self) -> T {
+        match self.is_empty() {
+            true => T::default(),
+            false => self.end - self.start,
+        }
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// The numbers in both intervals, or `None` if they don't have any in common
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_interval::Interval;
+    /// let a = Interval::new(2u64, 8);
+    /// assert_eq!(a.intersection(&Interval::new(5, 12)), Some(Interval::new(5, 8)));
+    /// assert_eq!(a.intersection(&Interval::new(8, 12)), None);
+    /// ```
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let overlap = Self::new(self.start.max(other.start), self.end.min(other.end));
+        (!overlap.is_empty()).then_some(overlap)
+    }
+
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Splits into the numbers below `at` and the rest, leaving out either side if it's empty
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_interval::Interval;
+    /// let a = Interval::new(2i64, 8);
+    /// assert_eq!(a.split_at(5), (Some(Interval::new(2, 5)), Some(Interval::new(5, 8))));
+    /// assert_eq!(a.split_at(2), (None, Some(a)));
+    /// assert_eq!(a.split_at(10), (Some(a), None));
+    /// ```
+    pub fn split_at(&self, at: T) -> (Option<Self>, Option<Self>) {
+        let below = Self::new(self.start, at.min(self.end));
+        let above = Self::new(at.max(self.start), self.end);
+        (
+            (!below.is_empty()).then_some(below),
+            (!above.is_empty()).then_some(above),
+        )
+    }
+
+    /// Moves both ends up by `by`, or down if it's negative
+    pub fn shift(&self, by: i64) -> Self {
+        Self::new(self.start.shift(by), self.end.shift(by))
+    }
+}
+
+/// Written like a Rust range, e.g. `2..8`
+impl<T: Display> Display for Interval<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}..{}", self.start, self.end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interval() {
+        let a = Interval::with_len(10u32, 5);
+        assert_eq!(a, Interval::new(10, 15));
+        assert_eq!(a.len(), 5);
+        assert!(a.contains(10) && a.contains(14) && !a.contains(15));
+        assert_eq!(a.shift(-10), Interval::new(0, 5));
+        assert_eq!(a.to_string(), "10..15");
+
+        let empty = Interval::new(7i32, 3);
+        assert!(empty.is_empty());
+        assert_eq!(empty.len(), 0);
+        assert!(!empty.contains(5));
+        assert!(!empty.overlaps(&Interval::new(0, 10)));
+        assert_eq!(empty.split_at(5), (None, None));
+    }
+
+    #[test]
+    #[should_panic(expected = "10 shifted by -11 is out of range")]
+    fn test_shift_out_of_range() {
+        Interval::new(10u64, 20).shift(-11);
+    }
+}
@@ -0,0 +1,38 @@
+//! Half-open intervals of integers, and sets of them, so puzzles that map huge ranges of numbers
+//! can split and move the ranges as a whole rather than going through every number in them.
+
+mod interval;
+mod set;
+
+pub use interval::Interval;
+pub use set::IntervalSet;
+
+use std::{
+    fmt::Debug,
+    ops::{Add, Sub},
+};
+
+/// An integer that can bound an interval
+pub trait Bound: Copy + Ord + Debug + Default + Add<Output = Self> + Sub<Output = Self> {
+    /// Moves the bound up by `by`, or down if it's negative
+    ///
+    /// # Panics
+    /// If that would take it out of the type's range
+    fn shift(self, by: i64) -> Self;
+}
+
+macro_rules! impl_bound {
+    ($($t:ty),*) => {
+        $(
+            impl Bound for $t {
+                fn shift(self, by: i64) -> Self {
+                    let shifted = self as i128 + by as i128;
+                    <$t>::try_from(shifted)
+                        .unwrap_or_else(|_| panic!("{} shifted by {} is out of range", self, by))
+                }
+            }
+        )*
+    };
+}
+
+impl_bound!(i32, i64, u32, u64, usize);
@@ -0,0 +1,295 @@
+use crate::{Bound, Interval};
+use std::fmt::Display;
+
+/// A set of numbers, kept as the fewest intervals that cover them. The intervals are in order, and
+/// any that overlap or touch are merged as they're added, so `2..5` and `5..8` become `2..8`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: Bound> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self {
+            intervals: Vec::new(),
+        }
+    }
+
+    /// Sorts and merges intervals in any order into a set
+    fn normalize(mut intervals: Vec<Interval<T>>) -> Self {
+        intervals.retain(|interval| !interval.is_empty());
+        intervals.sort_unstable_by_key(|interval| interval.start);
+        let mut merged: Vec<Interval<T>> = Vec::with_capacity(intervals.len());
+        for interval in intervals {
+            match merged.last_mut() {
+                Some(last) if interval.start <= last.end => last.end = last.end.max(interval.end),
+                _ => merged.push(interval),
+            }
+        }
+        Self { intervals: merged }
+    }
+
+    /// Adds the numbers in the interval, merging it with any it overlaps or touches
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_interval::{Interval, IntervalSet};
+    /// let mut set = IntervalSet::new();
+    /// set.insert(Interval::new(10u64, 12));
+    /// set.insert(Interval::new(2, 5));
+    /// assert_eq!(set.intervals(), &[Interval::new(2, 5), Interval::new(10, 12)]);
+    ///
+    /// set.insert(Interval::new(5, 10));
+    /// assert_eq!(set.intervals(), &[Interval::new(2, 12)]);
+    /// ```
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+        // The intervals from `first` up to `last` are the ones it overlaps or touches
+        let first = self
+            .intervals
+            .partition_point(|other| other.end < interval.start);
+        let last = self
+            .intervals
+            .partition_point(|other| other.start <= interval.end);
+        let merged = match first < last {
+            true => Interval::new(
+                interval.start.min(self.intervals[first].start),
+                interval.end.max(self.intervals[last - 1].end),
+            ),
+            false => interval,
+        };
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Interval<T>> {
+        self.intervals.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// How many numbers are in the set
+    pub fn len(&self) -> T {
+        self.intervals
+            .iter()
+            .fold(T::default(), |total, interval| total + interval.len())
+    }
+
+    /// The lowest number in the set
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|interval| interval.start)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self
+            .intervals
+            .partition_point(|interval| interval.end <= value);
+        self.intervals
+            .get(i)
+            .is_some_and(|interval| interval.contains(value))
+    }
+
+    /// The numbers in either set
+    pub fn union(&self, other: &Self) -> Self {
+        Self::normalize([self.intervals(), other.intervals()].concat())
+    }
+
+    /// The numbers in both sets
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_interval::{Interval, IntervalSet};
+    /// let a: IntervalSet<i64> = [Interval::new(0, 10), Interval::new(20, 30)].into_iter().collect();
+    /// let b: IntervalSet<i64> = [Interval::new(5, 25)].into_iter().collect();
+    /// assert_eq!(a.intersection(&b).intervals(), &[Interval::new(5, 10), Interval::new(20, 25)]);
+    /// ```
+    pub fn intersection(&self, other: &Self) -> Self {
+        let (mut i, mut j) = (0, 0);
+        let mut intervals = Vec::new();
+        while let (Some(a), Some(b)) = (self.intervals.get(i), other.intervals.get(j)) {
+            intervals.extend(a.intersection(b));
+            match a.end < b.end {
+                true => i += 1,
+                false => j += 1,
+            }
+        }
+        Self::normalize(intervals)
+    }
+
+    /// The numbers in this set that aren't in the other
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_interval::{Interval, IntervalSet};
+    /// let a: IntervalSet<i64> = Interval::new(0, 30).into();
+    /// let b: IntervalSet<i64> = [Interval::new(5, 10), Interval::new(20, 40)].into_iter().collect();
+    /// assert_eq!(a.difference(&b).intervals(), &[Interval::new(0, 5), Interval::new(10, 20)]);
+    /// ```
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+        let mut first = 0;
+        for interval in self.intervals.iter() {
+            // Anything ending before this interval ends before the rest of them too
+            while other
+                .intervals
+                .get(first)
+                .is_some_and(|removed| removed.end <= interval.start)
+            {
+                first += 1;
+            }
+            let mut start = interval.start;
+            for removed in other.intervals[first..]
+                .iter()
+                .take_while(|removed| removed.start < interval.end)
+            {
+                intervals.push(Interval::new(start, removed.start));
+                start = start.max(removed.end);
+            }
+            intervals.push(Interval::new(start, interval.end));
+        }
+        Self::normalize(intervals)
+    }
+
+    /// Splits into the numbers below `at` and the rest
+    pub fn split_at(&self, at: T) -> (Self, Self) {
+        let (below, above) = self
+            .intervals
+            .iter()
+            .map(|interval| interval.split_at(at))
+            .unzip::<_, _, Vec<_>, Vec<_>>();
+        (
+            Self::normalize(below.into_iter().flatten().collect()),
+            Self::normalize(above.into_iter().flatten().collect()),
+        )
+    }
+
+    /// Moves every number up by `by`, or down if it's negative
+    pub fn shift(&self, by: i64) -> Self {
+        Self {
+            intervals: self
+                .intervals
+                .iter()
+                .map(|interval| interval.shift(by))
+                .collect(),
+        }
+    }
+}
+
+impl<T: Bound> From<Interval<T>> for IntervalSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        Self::normalize(vec![interval])
+    }
+}
+
+impl<T: Bound> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(intervals: I) -> Self {
+        Self::normalize(intervals.into_iter().collect())
+    }
+}
+
+impl<T: Bound> Extend<Interval<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = Interval<T>>>(&mut self, intervals: I) {
+        for interval in intervals {
+            self.insert(interval);
+        }
+    }
+}
+
+/// Written like a set of Rust ranges, e.g. `{2..5, 8..10}`
+impl<T: Display> Display for IntervalSet<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{")?;
+        for (i, interval) in self.intervals.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", interval)?;
+        }
+        write!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Every number in the set, to check against
+    fn numbers(set: &IntervalSet<i64>) -> Vec<i64> {
+        set.iter()
+            .flat_map(|interval| interval.start..interval.end)
+            .collect()
+    }
+
+    fn set(intervals: &[(i64, i64)]) -> IntervalSet<i64> {
+        intervals
+            .iter()
+            .map(|&(start, end)| Interval::new(start, end))
+            .collect()
+    }
+
+    #[test]
+    fn test_normalize() {
+        let a = set(&[(8, 9), (0, 3), (2, 5), (5, 6), (7, 7), (10, 12)]);
+        assert_eq!(a.to_string(), "{0..6, 8..9, 10..12}");
+        assert_eq!(a.len(), 9);
+        assert_eq!(a.min(), Some(0));
+        assert!(a.contains(5) && a.contains(11) && !a.contains(6) && !a.contains(12));
+        assert_eq!(IntervalSet::<i64>::new().min(), None);
+    }
+
+    #[test]
+    fn test_operations() {
+        let a = set(&[(0, 10), (15, 20), (30, 40)]);
+        let b = set(&[(5, 17), (19, 35), (50, 60)]);
+        let in_a = |n| numbers(&a).contains(&n);
+        let in_b = |n| numbers(&b).contains(&n);
+        let all: Vec<i64> = (-5..65).collect();
+        let expected = |keep: &dyn Fn(i64) -> bool| -> Vec<i64> {
+            all.iter().copied().filter(|&n| keep(n)).collect()
+        };
+
+        assert_eq!(numbers(&a.union(&b)), expected(&|n| in_a(n) || in_b(n)));
+        assert_eq!(
+            numbers(&a.intersection(&b)),
+            expected(&|n| in_a(n) && in_b(n))
+        );
+        assert_eq!(
+            numbers(&a.difference(&b)),
+            expected(&|n| in_a(n) && !in_b(n))
+        );
+        assert_eq!(
+            numbers(&b.difference(&a)),
+            expected(&|n| in_b(n) && !in_a(n))
+        );
+
+        let (below, above) = a.split_at(17);
+        assert_eq!(below, set(&[(0, 10), (15, 17)]));
+        assert_eq!(above, set(&[(17, 20), (30, 40)]));
+        assert_eq!(below.union(&above), a);
+        assert_eq!(a.shift(-5), set(&[(-5, 5), (10, 15), (25, 35)]));
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut a = IntervalSet::new();
+        a.extend([Interval::new(10, 20), Interval::new(30, 40)]);
+        a.insert(Interval::new(0, 5));
+        a.insert(Interval::new(45, 50));
+        a.insert(Interval::new(15, 30));
+        assert_eq!(a, set(&[(0, 5), (10, 40), (45, 50)]));
+        a.insert(Interval::new(3, 3));
+        assert_eq!(a.intervals().len(), 3);
+        a.insert(Interval::new(-10, 60));
+        assert_eq!(a, set(&[(-10, 60)]));
+    }
+}
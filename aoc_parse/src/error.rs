@@ -0,0 +1,172 @@
+use aoc_core::Location;
+use nom::error::{ContextError, ErrorKind, FromExternalError, ParseError};
+use std::fmt::Display;
+
+/// What a parser was looking for when it gave up
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Expected {
+    /// A number, where there's one that doesn't fit its type or has letters stuck to it
+    Number,
+    /// Another row of a grid as wide as the first one
+    Width(usize),
+    /// Whatever the nom parser of this kind looks for
+    Nom(ErrorKind),
+}
+
+impl Display for Expected {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expected::Number => write!(f, "a number"),
+            Expected::Width(width) => write!(f, "a row {} wide", width),
+            Expected::Nom(kind) => write!(f, "{}", kind.description()),
+        }
+    }
+}
+
+/// Something labelled with nom's `context`, which the parser was in the middle of
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Context<'a> {
+    /// The input from where the labelled parser started
+    pub input: &'a str,
+    pub label: &'static str,
+}
+
+impl<'a> Context<'a> {
+    /// Locates where the labelled parser started, given the text that was parsed
+    pub fn location(&self, text: &str) -> Location {
+        Location::of(text, self.input)
+    }
+
+    /// The rest of the line from where the labelled parser started
+    pub fn line(&self) -> &'a str {
+        self.input.lines().next().unwrap_or_default().trim_end()
+    }
+}
+
+/// Where a parser gave up, and what it was looking for there
+///
+/// # Examples
+///
+/// ```
+/// use aoc_core::Location;
+/// use aoc_parse::{parse, unsigned};
+/// use nom::{bytes::complete::tag, error::context, sequence::*};
+/// let text = "Card 1: 41\nCard two: 83";
+/// let id = preceded(tag("Card "), unsigned::<u32>);
+/// let card = context("card", separated_pair(id, tag(": "), unsigned::<u32>));
+/// let error = parse(text, aoc_parse::lines(card)).unwrap_err();
+/// assert_eq!(error.location(text), Location::new(2, 6));
+/// assert_eq!(error.token(), "two");
+/// assert_eq!(error.context().unwrap().line(), "Card two: 83");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error<'a> {
+    /// The input from where it gave up
+    pub input: &'a str,
+    pub expected: Expected,
+    /// Everything it was in the middle of, innermost first
+    pub contexts: Vec<Context<'a>>,
+}
+
+impl<'a> Error<'a> {
+    pub fn new(input: &'a str, expected: Expected) -> Self {
+        Self {
+            input,
+            expected,
+            contexts: Vec::new(),
+        }
+    }
+
+    /// Locates where it gave up, given the text that was parsed
+    pub fn location(&self, text: &str) -> Location {
+        Location::of(text, self.input)
+    }
+
+    /// The word it gave up at, as in the number and any letters stuck to it, which is empty if it
+    /// gave up at punctuation or whitespace
+    pub fn token(&self) -> &'a str {
+        let sign = match self.input.starts_with(['-', '+']) {
+            true => 1,
+            false => 0,
+        };
+        let len = self.input[sign..]
+            .find(|c: char| !c.is_alphanumeric())
+            .unwrap_or(self.input.len() - sign);
+        &self.input[..sign + len]
+    }
+
+    /// The innermost thing it was in the middle of
+    pub fn context(&self) -> Option<&Context<'a>> {
+        self.contexts.first()
+    }
+}
+
+impl<'a> ParseError<&'a str> for Error<'a> {
+    fn from_error_kind(input: &'a str, kind: ErrorKind) -> Self {
+        Self::new(input, Expected::Nom(kind))
+    }
+
+    /// Keeps the innermost error, which is the most specific about what went wrong
+    fn append(_: &'a str, _: ErrorKind, other: Self) -> Self {
+        other
+    }
+}
+
+impl<'a> ContextError<&'a str> for Error<'a> {
+    fn add_context(input: &'a str, label: &'static str, mut other: Self) -> Self {
+        other.contexts.push(Context { input, label });
+        other
+    }
+}
+
+impl<'a, E> FromExternalError<&'a str, E> for Error<'a> {
+    fn from_external_error(input: &'a str, kind: ErrorKind, _: E) -> Self {
+        Self::from_error_kind(input, kind)
+    }
+}
+
+/// Written as what it expected, where, and what it was in the middle of, e.g.
+/// `expected a number at '1A 55' in seeds`
+impl Display for Error<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let at = self.input.lines().next().unwrap_or_default();
+        write!(f, "expected {} at '{}'", self.expected, at)?;
+        for context in self.contexts.iter() {
+            write!(f, " in {}", context.label)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for Error<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token() {
+        let token = |input| Error::new(input, Expected::Number).token();
+        assert_eq!(token("2l 14"), "2l");
+        assert_eq!(token("-20,"), "-20");
+        assert_eq!(token("+"), "+");
+        assert_eq!(token(": 41"), "");
+        assert_eq!(token("ünïcode!"), "ünïcode");
+        assert_eq!(token(""), "");
+    }
+
+    #[test]
+    fn test_display() {
+        let text = "seeds: 79 1A 55\nsoil";
+        let mut error = Error::new(&text[10..], Expected::Number);
+        error = Error::add_context(&text[7..], "list", error);
+        error = Error::add_context(text, "seeds", error);
+        assert_eq!(
+            error.to_string(),
+            "expected a number at '1A 55' in list in seeds"
+        );
+        assert_eq!(error.location(text), Location::new(1, 11));
+        assert_eq!(error.context().unwrap().location(text), Location::new(1, 8));
+        assert_eq!(error.contexts[1].line(), "seeds: 79 1A 55");
+    }
+}
@@ -0,0 +1,91 @@
+use crate::{lines, Error, Expected, IResult};
+use aoc_grid::Grid;
+use nom::{
+    character::complete::satisfy,
+    combinator::consumed,
+    error::{context, ContextError, ErrorKind::MapOpt},
+    multi::many1,
+};
+
+/// Parses a grid with a character for each cell, one row to a line, ignoring the indent and any
+/// trailing whitespace on each line
+///
+/// A character that `cell` gives `None` for fails in the middle of a `"row"` context, as does a
+/// row that's a different width to the first, which fails where it ends or where the first row
+/// did.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_parse::{grid, parse, Expected};
+/// let text = "#..\n.#.\n..#";
+/// let tiles = parse(text, grid(|c| Some(c == '#'))).unwrap();
+/// assert_eq!((tiles.width(), tiles.height()), (3, 3));
+/// assert_eq!(tiles[(2, 2)], true);
+///
+/// let error = parse("#..\n.#", grid(|c| Some(c))).unwrap_err();
+/// assert_eq!(error.expected, Expected::Width(3));
+/// assert_eq!(error.context().unwrap().line(), ".#");
+/// ```
+pub fn grid<'a, T>(
+    cell: impl Fn(char) -> Option<T>,
+) -> impl FnMut(&'a str) -> IResult<'a, Grid<T>> {
+    move |input| {
+        // Any character that isn't a cell is a mistake, rather than where the row ends
+        let cell = |input| {
+            let (rest, c) = satisfy(|c| !c.is_whitespace())(input)?;
+            match cell(c) {
+                Some(cell) => Ok((rest, cell)),
+                None => Err(nom::Err::Failure(Error::new(input, Expected::Nom(MapOpt)))),
+            }
+        };
+        let row = consumed(many1(cell));
+        let (input, rows) = lines(context("row", row))(input)?;
+
+        let width = rows[0].1.len();
+        if let Some((text, cells)) = rows.iter().find(|(_, cells)| cells.len() != width) {
+            let end = text
+                .char_indices()
+                .nth(width.min(cells.len()))
+                .map_or(text.len(), |(i, _)| i);
+            let error = Error::new(&text[end..], Expected::Width(width));
+            return Err(nom::Err::Failure(Error::add_context(text, "row", error)));
+        }
+        let rows = rows.into_iter().map(|(_, cells)| cells).collect();
+        let grid = Grid::from_rows(rows).expect("every row is as wide as the first");
+        Ok((input, grid))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+    use aoc_core::Location;
+
+    #[test]
+    fn test_grid() {
+        let text = "467..114..
+        ...*......
+        ..35..633.
+        ";
+        let schematic = parse(text, grid(Some)).unwrap();
+        assert_eq!(schematic.to_string(), "467..114..\n...*......\n..35..633.");
+
+        let text = "467..114..
+        ...*......
+        ..35..633";
+        let error = parse(text, grid(Some)).unwrap_err();
+        assert_eq!(error.location(text), Location::new(3, 18));
+        assert_eq!(error.context().unwrap().location(text), Location::new(3, 9));
+
+        let text = "467..114..\n...*......X";
+        let error = parse(text, grid(Some)).unwrap_err();
+        assert_eq!(error.location(text), Location::new(2, 11));
+
+        let text = "1.\n.2\n3?";
+        let error = parse(text, grid(|c| c.to_digit(10).or((c == '.').then_some(0)))).unwrap_err();
+        assert_eq!(error.expected, Expected::Nom(nom::error::ErrorKind::MapOpt));
+        assert_eq!(error.location(text), Location::new(3, 2));
+    }
+}
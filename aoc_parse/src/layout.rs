@@ -0,0 +1,171 @@
+use crate::{cut, Error, IResult};
+use nom::{
+    character::complete::{alphanumeric1, char, line_ending, multispace0, space0},
+    combinator::recognize,
+    multi::many1,
+    sequence::{delimited, pair, separated_pair, terminated, tuple},
+    Parser,
+};
+
+/// Parses the end of a line and at least one blank line after it, along with the indent of the
+/// line after those
+pub fn blank_lines(input: &str) -> IResult<'_, &str> {
+    recognize(tuple((
+        space0,
+        line_ending,
+        many1(pair(space0, line_ending)),
+        space0,
+    )))(input)
+}
+
+/// Parses a `key:` header and any whitespace after it, giving back what the key parser did
+///
+/// # Examples
+///
+/// ```
+/// use aoc_parse::{header, unsigned};
+/// use nom::{bytes::complete::tag, character::complete::space1, sequence::preceded};
+/// let mut card = header(preceded(tag("Card"), preceded(space1, unsigned::<u32>)));
+/// assert_eq!(card("Card  12: 41 48"), Ok(("41 48", 12)));
+/// assert_eq!(header(tag("seeds"))("seeds:\n79 14"), Ok(("79 14", "seeds")));
+/// ```
+pub fn header<'a, O>(
+    key: impl Parser<&'a str, O, Error<'a>>,
+) -> impl FnMut(&'a str) -> IResult<'a, O> {
+    terminated(key, pair(char(':'), multispace0))
+}
+
+/// Parses a node of a network, like `AAA = (BBB, CCC)`, into its name and the two it leads to
+///
+/// # Examples
+///
+/// ```
+/// use aoc_parse::node;
+/// assert_eq!(node("11A = (11B, XXX)\n"), Ok(("\n", ("11A", ("11B", "XXX")))));
+/// assert!(node("AAA = BBB, CCC").is_err());
+/// ```
+pub fn node(input: &str) -> IResult<'_, (&str, (&str, &str))> {
+    separated_pair(
+        alphanumeric1,
+        tuple((space0, char('='), space0)),
+        delimited(
+            pair(char('('), space0),
+            separated_pair(alphanumeric1, pair(char(','), space0), alphanumeric1),
+            pair(space0, char(')')),
+        ),
+    )(input)
+}
+
+/// Parses one item on each line, up to a blank line or the end, ignoring the indent and any
+/// trailing whitespace on each line
+///
+/// Only the first item can fail recoverably. After that an item that fails is a line in the
+/// wrong shape, rather than the end of the list, so the error is kept.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_parse::{lines, unsigned_list};
+/// let mut rows = lines(unsigned_list::<u32>);
+/// assert_eq!(rows("1 2\n  3 4 \n\n5"), Ok((" \n\n5", vec![vec![1, 2], vec![3, 4]])));
+/// assert!(rows("1 2\nx 3").is_err());
+/// ```
+pub fn lines<'a, O>(
+    mut item: impl Parser<&'a str, O, Error<'a>>,
+) -> impl FnMut(&'a str) -> IResult<'a, Vec<O>> {
+    move |input| {
+        let (mut input, first) = item.parse(space0(input)?.0)?;
+        let mut items = vec![first];
+        while let Ok((next, _)) = tuple((space0::<_, Error>, line_ending, space0))(input) {
+            if next.is_empty() || line_ending::<_, Error>(next).is_ok() {
+                break;
+            }
+            let (next, item) = item.parse(next).map_err(cut)?;
+            items.push(item);
+            input = next;
+        }
+        Ok((input, items))
+    }
+}
+
+/// Parses sections separated by blank lines, where only the first can fail recoverably like
+/// with [`lines`]
+///
+/// # Examples
+///
+/// ```
+/// use aoc_parse::{lines, sections, unsigned};
+/// let mut elves = sections(lines(unsigned::<u32>));
+/// assert_eq!(elves("1\n2\n\n3\n\n\n"), Ok(("\n\n\n", vec![vec![1, 2], vec![3]])));
+/// ```
+pub fn sections<'a, O>(
+    mut section: impl Parser<&'a str, O, Error<'a>>,
+) -> impl FnMut(&'a str) -> IResult<'a, Vec<O>> {
+    move |input| {
+        let (mut input, first) = section.parse(input)?;
+        let mut sections = vec![first];
+        while let Ok((next, _)) = blank_lines(input) {
+            if next.trim().is_empty() {
+                break;
+            }
+            let (next, section) = section.parse(next).map_err(cut)?;
+            sections.push(section);
+            input = next;
+        }
+        Ok((input, sections))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, unsigned, unsigned_list, Expected};
+    use nom::{bytes::complete::tag, error::context, sequence::preceded};
+
+    #[test]
+    fn test_lines() {
+        let text = "  1 2\r\n  3\n";
+        assert_eq!(
+            parse(text, lines(unsigned_list::<u8>)),
+            Ok(vec![vec![1, 2], vec![3]])
+        );
+
+        // A bad line after the first one is kept, rather than ending the list before it
+        let text = "1 2\n  3 4\n  5 six";
+        let error = parse(text, lines(context("row", unsigned_list::<u8>))).unwrap_err();
+        assert_eq!(error.expected, Expected::Nom(nom::error::ErrorKind::Eof));
+        assert_eq!(error.location(text), aoc_core::Location::new(3, 5));
+
+        let text = "1 2\n  three 4";
+        let row = |input| {
+            context(
+                "row",
+                separated_pair(unsigned::<u8>, char(' '), unsigned::<u8>),
+            )(input)
+        };
+        let error = parse(text, lines(row)).unwrap_err();
+        assert_eq!(error.context().unwrap().line(), "three 4");
+        assert_eq!(error.location(text), aoc_core::Location::new(2, 3));
+    }
+
+    #[test]
+    fn test_sections() {
+        let text = "seeds: 1 2\n\nsoil:\n  3\n  4\n   \n\nwater:\n  5\n\n";
+        let section = |input| pair(header(alphanumeric1), lines(unsigned::<u32>))(input);
+        let (seeds, maps) = parse(
+            text,
+            separated_pair(
+                preceded(header(tag("seeds")), unsigned_list::<u32>),
+                blank_lines,
+                sections(section),
+            ),
+        )
+        .unwrap();
+        assert_eq!(seeds, vec![1, 2]);
+        assert_eq!(maps, vec![("soil", vec![3, 4]), ("water", vec![5])]);
+
+        let text = "a:\n1\n\nb:\nx";
+        let error = parse(text, sections(section)).unwrap_err();
+        assert_eq!(error.location(text), aoc_core::Location::new(5, 1));
+    }
+}
@@ -0,0 +1,54 @@
+//! [nom] parsers for the shapes puzzle inputs keep coming in, so each day's parser only has to
+//! put them together. Errors remember where they happened, and what they were in the middle of
+//! parsing, so the days can turn them into their own errors with a [`Location`](aoc_core::Location).
+
+mod error;
+mod grid;
+mod layout;
+mod numbers;
+
+pub use error::{Context, Error, Expected};
+pub use grid::grid;
+pub use layout::{blank_lines, header, lines, node, sections};
+pub use numbers::{signed, signed_list, unsigned, unsigned_list};
+
+use nom::{
+    character::complete::multispace0, combinator::all_consuming, sequence::terminated, Parser,
+};
+
+/// The result of a parser in this crate
+pub type IResult<'a, O> = nom::IResult<&'a str, O, Error<'a>>;
+
+/// Runs the parser over the whole of `text`, which can only have whitespace left over after it
+///
+/// # Examples
+///
+/// ```
+/// use aoc_parse::{header, parse, unsigned_list, Expected};
+/// use nom::{bytes::complete::tag, sequence::preceded};
+/// let seeds = parse("seeds: 79 14 55 13\n", preceded(header(tag("seeds")), unsigned_list));
+/// assert_eq!(seeds, Ok(vec![79u32, 14, 55, 13]));
+///
+/// let error = parse("seeds: 79 1A 55", preceded(header(tag("seeds")), unsigned_list::<u32>));
+/// let error = error.unwrap_err();
+/// assert_eq!((error.expected, error.token()), (Expected::Number, "1A"));
+/// ```
+pub fn parse<'a, O>(
+    text: &'a str,
+    parser: impl Parser<&'a str, O, Error<'a>>,
+) -> Result<O, Error<'a>> {
+    match all_consuming(terminated(parser, multispace0))(text) {
+        Ok((_, output)) => Ok(output),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => Err(e),
+        Err(nom::Err::Incomplete(_)) => unreachable!("complete parsers never ask for more input"),
+    }
+}
+
+/// Stops alternatives from trying anything else after an error, since the input was the right
+/// shape up to here and only went wrong part way through
+pub(crate) fn cut<E>(error: nom::Err<E>) -> nom::Err<E> {
+    match error {
+        nom::Err::Error(e) => nom::Err::Failure(e),
+        e => e,
+    }
+}
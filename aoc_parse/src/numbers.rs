@@ -0,0 +1,95 @@
+use crate::{Error, Expected, IResult};
+use nom::{character::complete::space1, multi::separated_list1};
+use std::str::FromStr;
+
+/// Parses a whole number, without a sign
+///
+/// It gives a recoverable error if the input doesn't start with a digit, so lists and
+/// alternatives can stop there, but fails outright on a number like `1A` or one too big for `T`.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_parse::{unsigned, Expected};
+/// assert_eq!(unsigned::<u8>("42 blue"), Ok((" blue", 42)));
+/// assert!(matches!(unsigned::<u8>("-42"), Err(nom::Err::Error(_))));
+///
+/// let Err(nom::Err::Failure(error)) = unsigned::<u8>("420") else { panic!() };
+/// assert_eq!((error.expected, error.token()), (Expected::Number, "420"));
+/// ```
+pub fn unsigned<T: FromStr>(input: &str) -> IResult<'_, T> {
+    number(input, false)
+}
+
+/// Parses a whole number, which can start with a `-` or `+` sign
+///
+/// # Examples
+///
+/// ```
+/// use aoc_parse::signed;
+/// assert_eq!(signed::<i32>("-17, 4"), Ok((", 4", -17)));
+/// assert_eq!(signed::<i32>("+4"), Ok(("", 4)));
+/// ```
+pub fn signed<T: FromStr>(input: &str) -> IResult<'_, T> {
+    number(input, true)
+}
+
+/// Parses numbers separated by spaces on a single line, like `79 14  55 13`
+pub fn unsigned_list<T: FromStr>(input: &str) -> IResult<'_, Vec<T>> {
+    separated_list1(space1, unsigned)(input)
+}
+
+/// Parses numbers that can have a sign, separated by spaces on a single line, like `-2 0 14`
+pub fn signed_list<T: FromStr>(input: &str) -> IResult<'_, Vec<T>> {
+    separated_list1(space1, signed)(input)
+}
+
+fn number<T: FromStr>(input: &str, signed: bool) -> IResult<'_, T> {
+    let error = Error::new(input, Expected::Number);
+    let token = error.token();
+    let digits = match signed {
+        true => token.strip_prefix(['-', '+']).unwrap_or(token),
+        false => token,
+    };
+    if !digits.starts_with(|c: char| c.is_ascii_digit()) {
+        return Err(nom::Err::Error(Error::new(
+            input,
+            Expected::Nom(nom::error::ErrorKind::Digit),
+        )));
+    }
+    match token.parse() {
+        Ok(number) => Ok((&input[token.len()..], number)),
+        Err(_) => Err(nom::Err::Failure(error)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_number() {
+        assert_eq!(unsigned::<u64>("18446744073709551615"), Ok(("", u64::MAX)));
+        assert_eq!(signed::<i8>("-128"), Ok(("", -128)));
+        assert!(matches!(signed::<i8>("--1"), Err(nom::Err::Error(_))));
+        assert!(matches!(signed::<i8>("-x"), Err(nom::Err::Error(_))));
+        assert!(matches!(unsigned::<u8>(""), Err(nom::Err::Error(_))));
+        assert!(matches!(signed::<i8>("128"), Err(nom::Err::Failure(_))));
+        assert!(matches!(unsigned::<u32>("12.5"), Ok((".5", 12))));
+    }
+
+    #[test]
+    fn test_list() {
+        assert_eq!(
+            signed_list::<i64>("0 -3  6\t9 | 1"),
+            Ok((" | 1", vec![0, -3, 6, 9]))
+        );
+        assert_eq!(unsigned_list::<u32>("7\n8"), Ok(("\n8", vec![7])));
+
+        let Err(nom::Err::Failure(error)) = unsigned_list::<u32>("41 48 2l 14") else {
+            panic!("2l isn't a number")
+        };
+        assert_eq!(error.input, "2l 14");
+        assert_eq!(error.expected, Expected::Number);
+    }
+}
@@ -0,0 +1,16 @@
+use aoc_core::{Located, Location};
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ParseError {
+    #[error("{location}: '{text}' has no digits or spelled out numbers")]
+    NoNumbers { location: Location, text: String },
+}
+
+impl Located for ParseError {
+    fn location_mut(&mut self) -> &mut Location {
+        match self {
+            ParseError::NoNumbers { location, .. } => location,
+        }
+    }
+}
@@ -0,0 +1,51 @@
+//! Made up calibration documents that look like the real ones
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+/// The number of lines in the real inputs
+pub const SIZE: usize = 1000;
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// Makes up `size` lines of letters, digits and spelled out numbers, each with at least one digit
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut text = String::new();
+    for _ in 0..size {
+        let mut pieces: Vec<String> = vec![rng.gen_range(1..=9).to_string()];
+        for _ in 0..rng.gen_range(1..8) {
+            pieces.push(match rng.gen_range(0..3) {
+                0 => rng.gen_range(1..=9).to_string(),
+                1 => WORDS.choose(&mut rng).unwrap().to_string(),
+                _ => (0..rng.gen_range(1..6))
+                    .map(|_| rng.gen_range(b'a'..=b'z') as char)
+                    .collect(),
+            });
+        }
+        pieces.shuffle(&mut rng);
+        text += &pieces.concat();
+        text.push('\n');
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day01;
+    use aoc_core::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(7, 50), generate(7, 50));
+        assert_ne!(generate(7, 50), generate(8, 50));
+
+        let input_text = generate(7, 50);
+        assert_eq!(input_text.lines().count(), 50);
+        let day = Day01::parse(&input_text).unwrap();
+        assert!(day.part_one() > 0);
+        assert!(day.part_two() > 0);
+    }
+}
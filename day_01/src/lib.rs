@@ -0,0 +1,203 @@
+use aoc_core::{Located, Location, Solution};
+
+mod error;
+pub use error::ParseError;
+
+/// The examples from the puzzle description, with their answers in `examples/answers.toml`
+pub const EXAMPLES: aoc_core::Examples = aoc_core::examples!["example_1", "example_2"];
+
+pub mod generate;
+
+/// Made up inputs, for testing and benchmarking without the real ones
+pub const GENERATOR: aoc_core::Generator = aoc_core::Generator {
+    size: generate::SIZE,
+    generate: generate::generate,
+};
+
+#[derive(Debug)]
+pub struct Day01 {
+    lines: Vec<String>,
+}
+
+impl Solution for Day01 {
+    type PartOne = i32;
+    type PartTwo = i32;
+    type Error = ParseError;
+
+    fn parse(input_text: &str) -> Result<Self, ParseError> {
+        let lines = input_text
+            .lines()
+            .enumerate()
+            .map(|(i, line)| Day01::parse_line(line).map_err(|e| e.on_line(i + 1)))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { lines })
+    }
+
+    /// Sums the calibration values of every line, only counting digit characters
+    fn part_one(&self) -> i32 {
+        self.lines.iter().map(|line| get_num_chars(line)).sum()
+    }
+
+    /// Sums the calibration values of every line, counting both digits and spelled out numbers
+    fn part_two(&self) -> i32 {
+        self.lines.iter().map(|line| get_num_words(line)).sum()
+    }
+}
+
+impl Day01 {
+    /// Every line needs at least one number, or there's no calibration value to find
+    fn parse_line(line: &str) -> Result<String, ParseError> {
+        match get_num_words(line) {
+            0 => Err(ParseError::NoNumbers {
+                location: Location::new(1, 1),
+                text: line.to_string(),
+            }),
+            _ => Ok(line.to_string()),
+        }
+    }
+}
+
+/// Combines the first and last digit of the line into a two digit number, or 0 if it has no digits
+pub fn get_num_chars(s: &str) -> i32 {
+    let mut left = -1;
+    let mut right = -1;
+
+    for c in s.chars() {
+        match c {
+            '0'..='9' => {
+                right = c.to_digit(10).unwrap() as i32;
+                if left == -1 {
+                    left = right;
+                }
+            }
+            _ => continue,
+        }
+    }
+    if left == -1 {
+        return 0;
+    }
+
+    left * 10 + right
+}
+
+pub struct NumberMatch {
+    input: String,
+    output: i32,
+}
+
+impl NumberMatch {
+    pub fn new(input_str: &str, output: i32) -> Self {
+        Self {
+            input: String::from(input_str),
+            output,
+        }
+    }
+
+    pub fn matches(&self, input_str: &str) -> bool {
+        input_str.starts_with(&self.input)
+    }
+}
+
+/// Combines the first and last digit or spelled out number of the line into a two digit number, or 0 if it has neither
+pub fn get_num_words(s: &str) -> i32 {
+    let mut left = -1;
+    let mut right = -1;
+    let string = s.to_string();
+
+    let mut matches: Vec<NumberMatch> = vec![
+        NumberMatch::new("one", 1),
+        NumberMatch::new("two", 2),
+        NumberMatch::new("three", 3),
+        NumberMatch::new("four", 4),
+        NumberMatch::new("five", 5),
+        NumberMatch::new("six", 6),
+        NumberMatch::new("seven", 7),
+        NumberMatch::new("eight", 8),
+        NumberMatch::new("nine", 9),
+    ];
+
+    for i in 1..=9 {
+        matches.push(NumberMatch::new(&i.to_string(), i))
+    }
+
+    // Only start at character boundaries, since a line can have more than plain ASCII in it
+    for (index, _) in string.char_indices() {
+        let slice = &string[index..];
+        for m in matches.iter().filter(|m| m.matches(slice)) {
+            right = m.output;
+            if left == -1 {
+                left = right;
+            }
+        }
+    }
+
+    if left == -1 {
+        return 0;
+    }
+    left * 10 + right
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Part;
+
+    #[test]
+    fn test_num_chars() {
+        let inputs = EXAMPLES.input("example_1").lines().collect::<Vec<_>>();
+        let outputs = [12, 38, 15, 77];
+        let total: i32 = EXAMPLES.answer("example_1", Part::One);
+
+        let mut calc_total = 0;
+        for (input, o) in inputs.iter().zip(outputs.iter()) {
+            let calc_output = get_num_chars(input);
+            calc_total += calc_output;
+            println!("{} -> {}, expected {}", input, calc_output, o);
+            assert_eq!(calc_output, *o);
+        }
+        assert_eq!(calc_total, total);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input_text = "two1nine\nabcdef\n7pqrstsixteen";
+        assert_eq!(
+            Day01::parse(input_text).unwrap_err(),
+            ParseError::NoNumbers {
+                location: Location::new(2, 1),
+                text: "abcdef".to_string(),
+            }
+        );
+        assert!(Day01::parse("eightwothree").is_ok());
+    }
+
+    #[test]
+    fn test_num_words() {
+        let mut inputs = EXAMPLES.input("example_2").lines().collect::<Vec<_>>();
+        inputs.push("eighthree"); //Had to add this because it was an edge case not covered by the supplied tests
+        let outputs = [29, 83, 13, 24, 42, 14, 76, 83];
+        let total = EXAMPLES.answer::<i32>("example_2", Part::Two) + 83;
+
+        let mut calc_total = 0;
+        for (input, o) in inputs.iter().zip(outputs.iter()) {
+            let calc_output = get_num_words(input);
+            calc_total += calc_output;
+            println!("{} -> {}, expected {}", input, calc_output, o);
+            assert_eq!(calc_output, *o);
+        }
+        assert_eq!(calc_total, total);
+    }
+
+    #[test]
+    fn test_non_ascii() {
+        assert_eq!(get_num_words("ésix7ü"), 67);
+        assert_eq!(get_num_words("☃one☃"), 11);
+        assert_eq!(
+            Day01::parse("1abc2\nnöne").unwrap_err(),
+            ParseError::NoNumbers {
+                location: Location::new(2, 1),
+                text: "nöne".to_string(),
+            }
+        );
+    }
+}
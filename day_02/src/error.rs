@@ -0,0 +1,22 @@
+use aoc_core::{Located, Location};
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ParseError {
+    #[error("{location}: '{text}' is not a valid `Game <id>: ` header")]
+    InvalidHeader { location: Location, text: String },
+    #[error("{location}: '{text}' is not a valid cube count")]
+    InvalidCount { location: Location, text: String },
+    #[error("{location}: '{text}' is not red, green or blue")]
+    InvalidColor { location: Location, text: String },
+}
+
+impl Located for ParseError {
+    fn location_mut(&mut self) -> &mut Location {
+        match self {
+            ParseError::InvalidHeader { location, .. }
+            | ParseError::InvalidCount { location, .. }
+            | ParseError::InvalidColor { location, .. } => location,
+        }
+    }
+}
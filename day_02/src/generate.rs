@@ -0,0 +1,47 @@
+//! Made up game records that look like the real ones
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+/// The number of games in the real inputs
+pub const SIZE: usize = 100;
+
+const COLORS: [&str; 3] = ["red", "green", "blue"];
+
+/// Makes up `size` games, each with a few sets of up to 20 cubes of each color
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut text = String::new();
+    for id in 1..=size {
+        let sets: Vec<String> = (0..rng.gen_range(1..=6))
+            .map(|_| {
+                let count = rng.gen_range(1..=COLORS.len());
+                COLORS
+                    .choose_multiple(&mut rng, count)
+                    .map(|color| format!("{} {}", rng.gen_range(1..=20), color))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect();
+        text += &format!("Game {}: {}\n", id, sets.join("; "));
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day02;
+    use aoc_core::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(7, 20), generate(7, 20));
+        assert_ne!(generate(7, 20), generate(8, 20));
+
+        let input_text = generate(7, 20);
+        assert_eq!(input_text.lines().count(), 20);
+        let day = Day02::parse(&input_text).unwrap();
+        assert!(day.part_one() <= (1..=20).sum());
+        assert!(day.part_two() > 0);
+    }
+}
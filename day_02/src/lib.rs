@@ -0,0 +1,464 @@
+use aoc_core::{Located, Location, Solution};
+use aoc_parse::{header, unsigned};
+use derive_new::new;
+use nom::{
+    bytes::complete::{is_not, tag},
+    character::complete::{char, space0, space1},
+    combinator::cut,
+    error::context,
+    multi::separated_list1,
+    sequence::{pair, preceded, separated_pair},
+};
+use smart_default::SmartDefault;
+use std::{collections::HashMap, fmt::Display};
+use Color::*;
+
+mod error;
+pub use error::ParseError;
+
+/// The examples from the puzzle description, with their answers in `examples/answers.toml`
+pub const EXAMPLES: aoc_core::Examples = aoc_core::examples!["example"];
+
+pub mod generate;
+
+/// Made up inputs, for testing and benchmarking without the real ones
+pub const GENERATOR: aoc_core::Generator = aoc_core::Generator {
+    size: generate::SIZE,
+    generate: generate::generate,
+};
+
+#[derive(Debug)]
+pub struct Day02 {
+    games: Vec<Game>,
+}
+
+impl Solution for Day02 {
+    type PartOne = i32;
+    type PartTwo = i32;
+    type Error = ParseError;
+
+    fn parse(input_text: &str) -> Result<Self, ParseError> {
+        let games = input_text
+            .lines()
+            .enumerate()
+            .map(|(i, line)| Game::new(line).map_err(|e| e.on_line(i + 1)))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { games })
+    }
+
+    /// Sums the ids of every game that could have been played with 12 red, 13 green and 14 blue cubes
+    fn part_one(&self) -> i32 {
+        let given_set = Set {
+            colors: vec![
+                ColorCount::new(Red, 12),
+                ColorCount::new(Green, 13),
+                ColorCount::new(Blue, 14),
+            ],
+        };
+        self.games
+            .iter()
+            .filter(|game| game.validate(&given_set))
+            .map(|game| game.id)
+            .sum()
+    }
+
+    /// Sums the power of the minimum set of every game
+    fn part_two(&self) -> i32 {
+        self.games.iter().map(|game| game.min_set.get_power()).sum()
+    }
+}
+
+/// Turns an error from parsing `line` into the mistake it points at
+fn parse_error(line: &str, e: aoc_parse::Error) -> ParseError {
+    match e.context() {
+        Some(header) if header.label == "header" => ParseError::InvalidHeader {
+            location: header.location(line),
+            text: header.line().to_string(),
+        },
+        Some(count) if count.label == "count" => ParseError::InvalidCount {
+            location: e.location(line),
+            text: e.token().to_string(),
+        },
+        _ => ParseError::InvalidColor {
+            location: e.location(line),
+            text: e.token().to_string(),
+        },
+    }
+}
+
+#[derive(Debug, PartialEq, SmartDefault)]
+pub struct Game {
+    #[default(-1)]
+    id: i32,
+    #[default(Vec::new())]
+    sets: Vec<Set>,
+    min_set: Set,
+}
+
+impl Game {
+    /// Parses a single line, such as `Game 1: 3 blue, 4 red; 1 red, 2 green`
+    pub fn new(input: &str) -> Result<Game, ParseError> {
+        let id = context(
+            "header",
+            header(preceded(pair(tag("Game"), space1), unsigned)),
+        );
+        let sets = separated_list1(pair(char(';'), space0), Set::counts);
+        let (id, sets) = aoc_parse::parse(input, preceded(space0, pair(id, sets)))
+            .map_err(|e| parse_error(input, e))?;
+
+        let mut new_game = Game {
+            id,
+            ..Game::default()
+        };
+        for counts in sets {
+            new_game.add_set(Set::from_counts(input, counts)?);
+        }
+        new_game.min_set.create_min_set(&new_game.sets);
+        Ok(new_game)
+    }
+
+    pub fn add_set(&mut self, set: Set) {
+        self.sets.push(set);
+    }
+
+    pub fn validate(&self, given_set: &Set) -> bool {
+        self.sets.iter().all(|set| set.validate(given_set))
+    }
+}
+
+/// As a line of the input, like `Game 1: 3 blue, 4 red`
+impl Display for Game {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Game {}: ", self.id)?;
+        for (i, set) in self.sets.iter().enumerate() {
+            if i > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{}", set)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Default)]
+pub struct Set {
+    colors: Vec<ColorCount>,
+}
+
+impl Set {
+    /// Parses a set of cubes, such as `3 blue, 4 red`
+    pub fn new(input: &str) -> Result<Set, ParseError> {
+        let counts = aoc_parse::parse(input, preceded(space0, Set::counts))
+            .map_err(|e| parse_error(input, e))?;
+        Set::from_counts(input, counts)
+    }
+
+    /// Parses the count and name of each color, leaving the names to be checked
+    fn counts(input: &str) -> aoc_parse::IResult<'_, Vec<(i32, &str)>> {
+        // Each set and color starts with a count, so there's no going back once it's missing
+        let count = context("count", cut(unsigned));
+        let color = separated_pair(count, space1, is_not(",; \t\r\n"));
+        separated_list1(pair(char(','), space0), color)(input)
+    }
+
+    /// Creates the set from the counts of each color, with `line` only used to locate any errors
+    fn from_counts(line: &str, counts: Vec<(i32, &str)>) -> Result<Set, ParseError> {
+        let mut colors: Vec<ColorCount> = counts
+            .into_iter()
+            .map(|(count, name)| {
+                ColorCount::from_string(name, count).ok_or_else(|| ParseError::InvalidColor {
+                    location: Location::of(line, name),
+                    text: name.to_string(),
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        colors.sort();
+        Ok(Set { colors })
+    }
+
+    pub fn validate(&self, given_set: &Set) -> bool {
+        let validate = |other| self.colors.iter().any(|c| !c.validate(other));
+        !given_set.colors.iter().any(validate)
+    }
+
+    pub fn get_power(&self) -> i32 {
+        let power: i32 = self.colors.iter().map(|c| c.count).product();
+        power
+    }
+
+    fn create_min_set(&mut self, sets: &Vec<Set>) {
+        let mut counts: HashMap<Color, i32> = HashMap::new();
+        for set in sets {
+            for color in &set.colors {
+                let count = counts.entry(color.color).or_insert(color.count);
+                if *count < color.count {
+                    counts.entry(color.color).and_modify(|c| {
+                        *c = color.count;
+                    });
+                }
+            }
+        }
+        self.colors = counts
+            .iter()
+            .map(|(k, v)| ColorCount::new(*k, *v))
+            .collect();
+        self.colors.sort();
+    }
+}
+
+impl Display for Set {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, color) in self.colors.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", color)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd, new)]
+pub struct ColorCount {
+    color: Color,
+    count: i32,
+}
+
+impl ColorCount {
+    /// Create a new ColorCount from a string, or `None` if the string is not a valid color
+    pub fn from_string(color: &str, count: i32) -> Option<ColorCount> {
+        let color = match color {
+            "red" => Red,
+            "green" => Green,
+            "blue" => Blue,
+            _ => return None,
+        };
+        Some(ColorCount::new(color, count))
+    }
+
+    pub fn validate(&self, other: &ColorCount) -> bool {
+        other.color != self.color || self.count <= other.count
+    }
+}
+
+impl Display for ColorCount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.count, self.color)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Ord, PartialOrd, Hash, Clone, Copy)]
+pub enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+impl Display for Color {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Red => write!(f, "red"),
+            Green => write!(f, "green"),
+            Blue => write!(f, "blue"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Part;
+    use itertools::izip;
+    use proptest::prelude::*;
+
+    /// Between one and three distinct colors, in the order a parsed set keeps them
+    fn set_strategy() -> impl Strategy<Value = Set> {
+        proptest::sample::subsequence(vec![Red, Green, Blue], 1..=3)
+            .prop_flat_map(|colors| {
+                let count = colors.len();
+                (Just(colors), proptest::collection::vec(0..100i32, count))
+            })
+            .prop_map(|(colors, counts)| Set {
+                colors: colors
+                    .into_iter()
+                    .zip(counts)
+                    .map(|(color, count)| ColorCount::new(color, count))
+                    .collect(),
+            })
+    }
+
+    fn game_strategy() -> impl Strategy<Value = Game> {
+        (0..1000i32, proptest::collection::vec(set_strategy(), 1..8)).prop_map(|(id, sets)| {
+            let mut game = Game {
+                id,
+                sets,
+                ..Default::default()
+            };
+            game.min_set.create_min_set(&game.sets);
+            game
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn set_round_trip(set in set_strategy()) {
+            prop_assert_eq!(Set::new(&set.to_string()).unwrap(), set);
+        }
+
+        #[test]
+        fn game_round_trip(game in game_strategy()) {
+            prop_assert_eq!(Game::new(&game.to_string()).unwrap(), game);
+        }
+
+        #[test]
+        fn min_set_is_valid(game in game_strategy()) {
+            // Every set fits within the minimum set, and it's the smallest that does
+            prop_assert!(game.validate(&game.min_set));
+            for color in &game.min_set.colors {
+                let smaller = Set {
+                    colors: vec![ColorCount::new(color.color, color.count - 1)],
+                };
+                prop_assert!(!game.validate(&smaller));
+            }
+        }
+    }
+
+    #[test]
+    fn parse_set() {
+        let set_input = "9 green, 4 blue, 3 red";
+        let set = Set::new(set_input).unwrap();
+        assert_eq!(set.colors.len(), 3);
+        assert_eq!(
+            set.colors,
+            vec![
+                ColorCount::new(Red, 3),
+                ColorCount::new(Green, 9),
+                ColorCount::new(Blue, 4),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_game() {
+        let game_input = "Game 1: 1 green, 4 blue; 1 blue, 2 green, 1 red; 1 red, 1 green, 2 blue; 1 green, 1 red; 1 green; 1 green, 1 blue, 1 red";
+        let game = Game::new(game_input).unwrap();
+
+        let mut expected_game = Game {
+            id: 1,
+            sets: Vec::new(),
+            min_set: Set {
+                colors: vec![
+                    ColorCount::new(Red, 1),
+                    ColorCount::new(Green, 2),
+                    ColorCount::new(Blue, 4),
+                ],
+            },
+        };
+
+        expected_game.add_set(Set::new("1 green, 4 blue").unwrap());
+        expected_game.add_set(Set::new("1 blue, 2 green, 1 red").unwrap());
+        expected_game.add_set(Set::new("1 red, 1 green, 2 blue").unwrap());
+        expected_game.add_set(Set::new("1 green, 1 red").unwrap());
+        expected_game.add_set(Set::new("1 green").unwrap());
+        expected_game.add_set(Set::new("1 green, 1 blue, 1 red").unwrap());
+
+        assert_eq!(game.sets.len(), 6);
+        for (set, expected_set) in game.sets.iter().zip(expected_game.sets.iter()) {
+            assert_eq!(set, expected_set);
+        }
+        assert_eq!(expected_game.min_set, game.min_set);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let input_text = "Game 1: 3 blue, 4 red\nGame 2: 1 blue, 2 purple";
+        assert_eq!(
+            Day02::parse(input_text).unwrap_err(),
+            ParseError::InvalidColor {
+                location: Location::new(2, 19),
+                text: "purple".to_string(),
+            }
+        );
+        assert_eq!(
+            Game::new("Game 3: 1 blue; two red").unwrap_err(),
+            ParseError::InvalidCount {
+                location: Location::new(1, 17),
+                text: "two".to_string(),
+            }
+        );
+        assert_eq!(
+            Game::new("Round 4: 1 blue").unwrap_err(),
+            ParseError::InvalidHeader {
+                location: Location::new(1, 1),
+                text: "Round 4: 1 blue".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn validate_game() {
+        let given_set = Set::new("12 red, 13 green, 14 blue").unwrap();
+
+        let valid_game =
+            Game::new("Game 2: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green").unwrap();
+        let invalid_game =
+            Game::new("Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red")
+                .unwrap();
+
+        assert!(valid_game.validate(&given_set));
+        assert!(!invalid_game.validate(&given_set));
+    }
+
+    #[test]
+    fn valid_games() {
+        let given_set = Set::new("12 red, 13 green, 14 blue").unwrap();
+        let expected_output: i32 = EXAMPLES.answer("example", Part::One);
+
+        let input = EXAMPLES.input("example");
+
+        let games: Vec<Game> = input.lines().map(|line| Game::new(line).unwrap()).collect();
+
+        let mut output = 0;
+        games
+            .iter()
+            .filter(|game| game.validate(&given_set))
+            .for_each(|game| {
+                output += game.id;
+            });
+        assert_eq!(expected_output, output);
+    }
+
+    #[test]
+    fn min_sets() {
+        let input = EXAMPLES.input("example");
+
+        let min_sets: Vec<Set> = vec![
+            "4 red, 2 green, 6 blue",
+            "1 red, 3 green, 4 blue",
+            "20 red, 13 green, 6 blue",
+            "14 red, 3 green, 15 blue",
+            "6 red, 3 green, 2 blue",
+        ]
+        .into_iter()
+        .map(|set| Set::new(set).unwrap())
+        .collect();
+
+        let powers = vec![48, 12, 1560, 630, 36];
+        let expected_total_power: i32 = EXAMPLES.answer("example", Part::Two);
+        let mut total_power = 0;
+
+        let games: Vec<Game> = input.lines().map(|line| Game::new(line).unwrap()).collect();
+        izip!(games, min_sets, powers).for_each(|(game, min_set, power)| {
+            // println!("{}: {:?} should be {:?}", game.id, game.min_set, min_set);
+            assert_eq!(game.min_set, min_set);
+
+            let calc_power = game.min_set.get_power();
+            // println!("{}: {} should be {}", game.id, calc_power, power);
+            assert_eq!(calc_power, power);
+
+            total_power += calc_power;
+        });
+
+        assert_eq!(expected_total_power, total_power);
+    }
+}
@@ -0,0 +1,18 @@
+use aoc_core::Location;
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ParseError {
+    #[error("{location}: '{text}' should be {expected} characters wide like the first line")]
+    Ragged {
+        location: Location,
+        text: String,
+        expected: usize,
+    },
+    #[error(
+        "{location}: '{text}' is not a row of the schematic, which has to be one block of lines"
+    )]
+    InvalidRow { location: Location, text: String },
+    #[error("{location}: '{text}' is too large to be a part number")]
+    InvalidNumber { location: Location, text: String },
+}
@@ -0,0 +1,60 @@
+//! Made up engine schematics that look like the real ones
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+/// The width and height of the real inputs
+pub const SIZE: usize = 140;
+
+const SYMBOLS: [char; 10] = ['*', '#', '+', '$', '/', '@', '%', '=', '&', '-'];
+
+/// Makes up a `size` by `size` schematic of numbers up to 3 digits long, scattered among symbols.
+/// Gears show up often enough that most of them are next to two numbers.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut text = String::new();
+    for _ in 0..size {
+        let mut row = String::new();
+        while row.len() < size {
+            let left = size - row.len();
+            match rng.gen_range(0..10) {
+                0..=1 => {
+                    let digits = rng.gen_range(1..=3).min(left);
+                    let number =
+                        rng.gen_range(10usize.pow(digits as u32 - 1)..10usize.pow(digits as u32));
+                    row += &number.to_string();
+                    if row.len() < size {
+                        row.push('.');
+                    }
+                }
+                2 => row.push(match rng.gen_bool(0.4) {
+                    true => '*',
+                    false => *SYMBOLS.choose(&mut rng).unwrap(),
+                }),
+                _ => row.push('.'),
+            }
+        }
+        text += &row;
+        text.push('\n');
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day03;
+    use aoc_core::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(7, 30), generate(7, 30));
+        assert_ne!(generate(7, 30), generate(8, 30));
+
+        let input_text = generate(7, 30);
+        assert!(input_text.lines().all(|line| line.len() == 30));
+        assert_eq!(input_text.lines().count(), 30);
+        let day = Day03::parse(&input_text).unwrap();
+        assert!(day.part_one() > 0);
+        assert!(day.part_two() > 0);
+    }
+}
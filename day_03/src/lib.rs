@@ -0,0 +1,337 @@
+use aoc_core::{Location, Solution};
+use aoc_geometry::{Line, Vec2};
+use aoc_grid::{Grid, Pos};
+use aoc_parse::Expected;
+use derive_new::new;
+
+mod error;
+pub use error::ParseError;
+
+/// The examples from the puzzle description, with their answers in `examples/answers.toml`
+pub const EXAMPLES: aoc_core::Examples = aoc_core::examples!["example"];
+
+pub mod generate;
+
+/// Made up inputs, for testing and benchmarking without the real ones
+pub const GENERATOR: aoc_core::Generator = aoc_core::Generator {
+    size: generate::SIZE,
+    generate: generate::generate,
+};
+
+#[derive(Debug)]
+pub struct Day03 {
+    schematic: Schematic,
+}
+
+impl Solution for Day03 {
+    type PartOne = i32;
+    type PartTwo = i32;
+    type Error = ParseError;
+
+    fn parse(input_text: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            schematic: Schematic::new(input_text)?,
+        })
+    }
+
+    fn part_one(&self) -> i32 {
+        self.schematic.get_parts_total()
+    }
+
+    fn part_two(&self) -> i32 {
+        self.schematic.get_gears_total()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Schematic {
+    grid: Grid<char>,
+    parts: Vec<PartNumber>,
+    symbols: Vec<Symbol>,
+}
+
+impl Schematic {
+    pub fn new(input_text: &str) -> Result<Self, ParseError> {
+        let grid = aoc_parse::parse(input_text, aoc_parse::grid(Some)).map_err(|e| {
+            match (e.expected, e.context()) {
+                (Expected::Width(expected), Some(row)) => ParseError::Ragged {
+                    location: e.location(input_text),
+                    text: row.line().to_string(),
+                    expected,
+                },
+                _ => ParseError::InvalidRow {
+                    location: e.location(input_text),
+                    text: e
+                        .input
+                        .lines()
+                        .next()
+                        .unwrap_or_default()
+                        .trim()
+                        .to_string(),
+                },
+            }
+        })?;
+
+        let symbols = grid
+            .find_all(|c| Schematic::is_symbol(*c))
+            .map(|pos| Symbol {
+                symbol: grid[pos],
+                pos: Vec2::from_pos(pos),
+            })
+            .collect();
+
+        let mut parts = Vec::new();
+        for (y, row) in grid.rows().enumerate() {
+            let mut start = None;
+            // One past the end, so numbers touching the right edge are closed off too
+            for x in 0..=row.len() {
+                let is_digit = row.get(x).is_some_and(char::is_ascii_digit);
+                match (is_digit, start) {
+                    (true, None) => start = Some(x),
+                    (false, Some(start_x)) => {
+                        let digits = &row[start_x..x];
+                        let part = PartNumber::new(digits, start_x, y).ok_or_else(|| {
+                            ParseError::InvalidNumber {
+                                location: Schematic::locate(input_text, start_x, y),
+                                text: digits.iter().collect(),
+                            }
+                        })?;
+                        parts.push(part);
+                        start = None;
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        for part in parts.iter_mut() {
+            part.near_symbol = part
+                .positions()
+                .flat_map(|pos| grid.neighbours8(pos))
+                .any(|pos| Schematic::is_symbol(grid[pos]));
+        }
+
+        Ok(Schematic {
+            grid,
+            parts,
+            symbols,
+        })
+    }
+
+    /// Finds where the grid position `(x, y)` came from in the input text
+    fn locate(input_text: &str, x: usize, y: usize) -> Location {
+        let line = input_text
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .nth(y)
+            .unwrap_or_default();
+        let mut location = Location::of(input_text, line);
+        location.column += x;
+        location
+    }
+
+    fn is_symbol(c: char) -> bool {
+        c != '.' && !c.is_ascii_digit()
+    }
+
+    pub fn get_parts_total(&self) -> i32 {
+        self.parts
+            .iter()
+            .filter_map(|p| match p.near_symbol {
+                true => Some(p.number),
+                _ => None,
+            })
+            .sum()
+    }
+
+    pub fn get_gears(&self) -> Vec<Gear> {
+        let mut gears = Vec::new();
+
+        let potential_gears: Vec<&Symbol> =
+            self.symbols.iter().filter(|s| s.symbol == '*').collect();
+
+        for g in potential_gears.iter() {
+            let nearby: Vec<&PartNumber> = self.parts.iter().filter(|p| p.touches(g.pos)).collect();
+
+            let count = nearby.len();
+            if count == 2 {
+                gears.push(Gear::new(g.pos, *nearby[0], *nearby[1]));
+            }
+        }
+        gears
+    }
+
+    pub fn get_gears_total(&self) -> i32 {
+        self.get_gears()
+            .iter()
+            .map(|g| g.part_a.number * g.part_b.number)
+            .sum()
+    }
+
+    pub fn print(&self) {
+        println!("{}", self.grid);
+
+        for (i, part) in self.parts.iter().enumerate() {
+            println!("Part Number #{i}: {:?}", part);
+        }
+
+        for (i, symbol) in self.symbols.iter().enumerate() {
+            println!("Symbol #{i}: {:?}", symbol);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PartNumber {
+    number: i32,
+    /// From the first digit to the last
+    digits: Line,
+    near_symbol: bool,
+}
+
+impl PartNumber {
+    /// Creates the part number from its digits, or `None` if they're too large
+    fn new(digits: &[char], x: usize, y: usize) -> Option<Self> {
+        let number = digits.iter().collect::<String>().parse().ok()?;
+        Some(Self {
+            number,
+            digits: Line::new(
+                Vec2::from_pos((x, y)),
+                Vec2::from_pos((x + digits.len() - 1, y)),
+            ),
+            near_symbol: false,
+        })
+    }
+
+    /// The grid positions of every digit of the number
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        self.digits.points().filter_map(Vec2::to_pos)
+    }
+
+    /// Whether the position is next to any of the digits, including diagonally
+    pub fn touches(&self, pos: Vec2) -> bool {
+        self.digits.bounds().expand(1).contains(pos)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Symbol {
+    symbol: char,
+    pos: Vec2,
+}
+
+#[derive(Debug, Clone, new)]
+pub struct Gear {
+    pub pos: Vec2,
+    part_a: PartNumber,
+    part_b: PartNumber,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_a() {
+        let input_text = EXAMPLES.input("example");
+
+        let expected_parts = [
+            (467, true),
+            (114, false),
+            (35, true),
+            (633, true),
+            (617, true),
+            (58, false),
+            (592, true),
+            (755, true),
+            (664, true),
+            (598, true),
+        ];
+
+        let schematic = Schematic::new(input_text).unwrap();
+        schematic.print();
+
+        for (i, part) in schematic.parts.iter().enumerate() {
+            println!(
+                "Part Number #{} is near {} | Expected {}",
+                part.number, part.near_symbol, expected_parts[i].1
+            );
+            assert_eq!(expected_parts[i].0, part.number);
+            assert_eq!(expected_parts[i].1, part.near_symbol);
+        }
+
+        let outcome = schematic.get_parts_total();
+
+        let expected_outcome: i32 = expected_parts
+            .iter()
+            .map(|f| match f.1 {
+                true => f.0,
+                _ => 0,
+            })
+            .sum();
+        assert_eq!(expected_outcome, outcome);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let input_text = "467..114..
+        ...*......
+        ..35..633";
+        assert_eq!(
+            Schematic::new(input_text).unwrap_err(),
+            ParseError::Ragged {
+                location: Location::new(3, 18),
+                text: "..35..633".to_string(),
+                expected: 10,
+            }
+        );
+
+        let input_text = "467..114..\n\n...*......";
+        assert_eq!(
+            Schematic::new(input_text).unwrap_err(),
+            ParseError::InvalidRow {
+                location: Location::new(3, 1),
+                text: "...*......".to_string(),
+            }
+        );
+
+        let input_text = "467..........
+        ...*.........
+        .99999999999.";
+        assert_eq!(
+            Schematic::new(input_text).unwrap_err(),
+            ParseError::InvalidNumber {
+                location: Location::new(3, 10),
+                text: "99999999999".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_b() {
+        let input_text = EXAMPLES.input("example");
+
+        let expected_gears = [(Vec2::new(3, 1), 16345), (Vec2::new(5, 8), 451490)];
+
+        let schematic = Schematic::new(input_text).unwrap();
+        schematic.print();
+
+        let gears = schematic.get_gears();
+        println!("Found {} gears", gears.len());
+
+        for (gear, expected) in gears.iter().zip(expected_gears.iter()) {
+            println!(
+                "Gear at {:?} has value {} | Expected {}",
+                gear.pos,
+                gear.part_a.number * gear.part_b.number,
+                expected.1
+            );
+            assert_eq!(gear.pos, expected.0);
+            assert_eq!(gear.part_a.number * gear.part_b.number, expected.1);
+        }
+
+        let outcome = schematic.get_gears_total();
+        let expected_outcome: i32 = expected_gears.iter().map(|f| f.1).sum();
+        assert_eq!(expected_outcome, outcome);
+    }
+}
@@ -0,0 +1,24 @@
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use day_04::{CardManager, GENERATOR};
+
+/// Counting the card copies in one pass, against playing out every round of copies
+fn waterfall_vs_rounds(c: &mut Criterion) {
+    let input_text = GENERATOR.input(2023);
+    let card_manager = CardManager::new(&input_text).unwrap();
+
+    let mut group = c.benchmark_group("day_04_copies");
+    // Playing out the rounds takes long enough that the default 100 samples drag on
+    group.sample_size(10);
+    group.bench_function("waterfall", |b| b.iter(|| card_manager.waterfall_rounds()));
+    group.bench_function("rounds", |b| {
+        b.iter_batched(
+            || card_manager.clone(),
+            |mut card_manager| card_manager.play_all_rounds(),
+            BatchSize::LargeInput,
+        )
+    });
+    group.finish();
+}
+
+criterion_group!(benches, waterfall_vs_rounds);
+criterion_main!(benches);
@@ -0,0 +1,28 @@
+use aoc_core::{Located, Location};
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ParseError {
+    #[error("{location}: '{text}' is not a `Card <id>: <winners> | <numbers>` line")]
+    InvalidCard { location: Location, text: String },
+    #[error("{location}: '{text}' is not a valid card id")]
+    InvalidId { location: Location, text: String },
+    #[error("{location}: '{text}' is not a valid number")]
+    InvalidNumber { location: Location, text: String },
+    #[error("{location}: '{text}' wins copies of cards that don't exist")]
+    WinsMissingCards { location: Location, text: String },
+    #[error("{location}: '{text}' wins too many times to be scored")]
+    TooManyWins { location: Location, text: String },
+}
+
+impl Located for ParseError {
+    fn location_mut(&mut self) -> &mut Location {
+        match self {
+            ParseError::InvalidCard { location, .. }
+            | ParseError::InvalidId { location, .. }
+            | ParseError::InvalidNumber { location, .. }
+            | ParseError::WinsMissingCards { location, .. }
+            | ParseError::TooManyWins { location, .. } => location,
+        }
+    }
+}
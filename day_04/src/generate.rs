@@ -0,0 +1,79 @@
+//! Made up scratchcards that look like the real ones
+
+use rand::{rngs::StdRng, seq::index, seq::SliceRandom, Rng, SeedableRng};
+
+/// The number of cards in the real inputs
+pub const SIZE: usize = 200;
+
+const WINNERS: usize = 10;
+const NUMBERS: usize = 25;
+/// Roughly how many copies the real inputs end up with for each card. The copies otherwise grow
+/// exponentially, and soon overflow.
+const COPIES_PER_CARD: u64 = 60_000;
+
+/// Makes up `size` cards of 10 winning numbers and 25 numbers, where how many of them win is
+/// spread evenly like the real inputs. No card wins copies of cards past the end.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    // How many of each card there'll be, and of every card altogether
+    let mut copies = vec![1u64; size];
+    let mut total = size as u64;
+    let mut text = String::new();
+    for i in 0..size {
+        let held = copies[i];
+        let budget = (i as u64 + 1) * COPIES_PER_CARD;
+        let wins = rng
+            .gen_range(0..=WINNERS)
+            .min(size - i - 1)
+            .min((budget.saturating_sub(total) / held) as usize);
+        for card in copies.iter_mut().skip(i + 1).take(wins) {
+            *card += held;
+        }
+        total += held * wins as u64;
+
+        // The winning numbers come first, then the rest of the numbers are picked from the same
+        // 1 to 99 without repeats, of which the first `wins` are the ones that match
+        let picked = index::sample(&mut rng, 99, WINNERS + NUMBERS - wins).into_vec();
+        let winners: Vec<usize> = picked[..WINNERS].iter().map(|n| n + 1).collect();
+        let mut numbers: Vec<usize> = winners[..wins].to_vec();
+        numbers.extend(picked[WINNERS..].iter().map(|n| n + 1));
+        numbers.shuffle(&mut rng);
+
+        let format = |numbers: &[usize]| {
+            numbers
+                .iter()
+                .map(|n| format!("{:>2}", n))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        text += &format!(
+            "Card {:>3}: {} | {}\n",
+            i + 1,
+            format(&winners),
+            format(&numbers)
+        );
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CardManager, Day04};
+    use aoc_core::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(7, 50), generate(7, 50));
+        assert_ne!(generate(7, 50), generate(8, 50));
+
+        let input_text = generate(7, 50);
+        let day = Day04::parse(&input_text).unwrap();
+        assert!(day.part_one() > 0);
+        let mut card_manager = CardManager::new(&input_text).unwrap();
+        assert_eq!(
+            card_manager.waterfall_rounds(),
+            card_manager.play_all_rounds()
+        );
+    }
+}
@@ -1,31 +1,54 @@
+use aoc_core::{Located, Location, Solution};
+use aoc_parse::{header, unsigned, unsigned_list, Expected};
 use itertools::Itertools;
-use regex::Regex;
-use std::{collections::HashMap, time};
-use tmx_utils::string_ext;
-
-#[allow(dead_code)]
-pub fn new_main() {
-    let input_text = string_ext::read_local_file("input.txt").unwrap();
-    let card_manager = CardManager::new(&input_text);
-    let _total = card_manager.waterfall_rounds();
-    // println!("Second Total: {}", card_manager.waterfall_rounds());
+use nom::{
+    bytes::complete::tag,
+    character::complete::{char, space0, space1},
+    error::context,
+    sequence::{pair, preceded, separated_pair, tuple},
+};
+use std::{collections::HashMap, fmt::Display};
+
+mod error;
+pub use error::ParseError;
+
+/// The examples from the puzzle description, with their answers in `examples/answers.toml`
+pub const EXAMPLES: aoc_core::Examples = aoc_core::examples!["example"];
+
+pub mod generate;
+
+/// Made up inputs, for testing and benchmarking without the real ones
+pub const GENERATOR: aoc_core::Generator = aoc_core::Generator {
+    size: generate::SIZE,
+    generate: generate::generate,
+};
+
+#[derive(Debug)]
+pub struct Day04 {
+    card_manager: CardManager,
 }
 
-#[allow(dead_code)]
-pub fn old_main() {
-    let input_text = string_ext::read_local_file("input.txt").unwrap();
-    let mut card_manager = CardManager::new(&input_text);
-    let _total = card_manager.play_all_rounds();
-    // let total = card_manager
-    //     .cards_lut
-    //     .values()
-    //     .map(|x| x.score)
-    //     .sum::<i32>();
-    // println!("First Total: {}", total);
-    // println!("Second Total: {}", card_manager.play_all_rounds());
+impl Solution for Day04 {
+    type PartOne = i32;
+    type PartTwo = i32;
+    type Error = ParseError;
+
+    fn parse(input_text: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            card_manager: CardManager::new(input_text)?,
+        })
+    }
+
+    fn part_one(&self) -> i32 {
+        self.card_manager.get_score_total()
+    }
+
+    fn part_two(&self) -> i32 {
+        self.card_manager.waterfall_rounds()
+    }
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CardManager {
     cards_vec: Vec<Card>,
     cards_lut: HashMap<i32, Card>,
@@ -33,42 +56,55 @@ pub struct CardManager {
 }
 
 impl CardManager {
-    pub fn new(input_text: &str) -> Self {
-        let cards_vec = input_text.lines().map(Card::new).collect_vec();
+    pub fn new(input_text: &str) -> Result<Self, ParseError> {
+        let cards_vec: Vec<Card> = input_text
+            .lines()
+            .enumerate()
+            .map(|(i, line)| Card::new(line).map_err(|e| e.on_line(i + 1)))
+            .try_collect()?;
         let remaining_cards = cards_vec.iter().map(|x| x.id).collect_vec();
         let mut cards = HashMap::new();
         for card in cards_vec.clone() {
             cards.insert(card.id, card);
         }
-        Self {
+
+        // Every card a card wins a copy of has to be there to be copied
+        let lines = input_text.lines().enumerate();
+        for ((i, line), card) in lines.zip(cards_vec.iter()) {
+            if (card.id + 1..=card.id + card.win_count).any(|id| !cards.contains_key(&id)) {
+                let error = ParseError::WinsMissingCards {
+                    location: Location::of(line, line.trim_start()),
+                    text: line.trim().to_string(),
+                };
+                return Err(error.on_line(i + 1));
+            }
+        }
+        Ok(Self {
             cards_vec,
             cards_lut: cards,
             remaining_cards,
-        }
+        })
+    }
+
+    pub fn get_score_total(&self) -> i32 {
+        self.cards_vec.iter().map(|c| c.score).sum()
     }
 
     pub fn waterfall_rounds(&self) -> i32 {
-        let start = time::Instant::now();
         let mut copies: HashMap<i32, i32> =
             HashMap::from_iter(self.cards_vec.iter().map(|c| (c.id, 1)));
         for card in &self.cards_vec {
             if card.win_count == 0 {
                 continue;
             }
-            let current_card_copies = *copies.get(&card.id).unwrap();
+            let current_card_copies = copies[&card.id];
             for other_index in card.id + 1..=card.id + card.win_count {
-                // *copies.entry(other_index).or_insert(1) += current_card_copies;
-                copies.insert(
-                    other_index,
-                    copies.get(&other_index).unwrap() + current_card_copies,
-                );
+                // The cards won were checked to exist when parsing
+                copies.insert(other_index, copies[&other_index] + current_card_copies);
             }
         }
 
-        let sum = copies.values().sum();
-        let end = time::Instant::now();
-        println!("Waterfall took {}us", end.duration_since(start).as_micros());
-        sum
+        copies.values().sum()
     }
 
     pub fn play_round(&mut self) {
@@ -92,26 +128,19 @@ impl CardManager {
     }
 
     pub fn play_all_rounds(&mut self) -> i32 {
-        let start = time::Instant::now();
         let mut total_cards = vec![];
         let mut loop_count = 0;
         while loop_count < 100 && !self.remaining_cards.is_empty() {
             loop_count += 1;
             total_cards.append(&mut self.remaining_cards.clone());
-            // println!(
-            //     "Round {loop_count}: {} total cards, {} cards remaining",
-            //     total_cards.len(),
-            //     self.remaining_cards.len()
-            // );
+            log::trace!(
+                "Round {loop_count}: {} total cards, {} cards remaining",
+                total_cards.len(),
+                self.remaining_cards.len()
+            );
             self.play_round();
         }
-        let sum = total_cards.len() as i32;
-        let end = time::Instant::now();
-        println!(
-            "Rounds {loop_count}: took {}ms",
-            end.duration_since(start).as_millis()
-        );
-        sum
+        total_cards.len() as i32
     }
 }
 
@@ -125,50 +154,101 @@ pub struct Card {
 }
 
 impl Card {
-    pub fn new(input: &str) -> Self {
-        let re = Regex::new(r"Card *(?<CardID>\d*): *(?<Winners>.*) \| *(?<Numbers>.*)").unwrap();
-        let captures = re.captures(input).unwrap();
-        let id = captures
-            .name("CardID")
-            .unwrap()
-            .as_str()
-            .trim()
-            .parse::<i32>()
-            .unwrap();
-
-        let winners = Card::parse_nums(&captures, "Winners");
-        let numbers = Card::parse_nums(&captures, "Numbers");
+    /// Parses a single line, such as `Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53`
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let id = context("id", preceded(pair(tag("Card"), space1), unsigned));
+        let numbers = separated_pair(
+            unsigned_list,
+            tuple((space1, char('|'), space1)),
+            unsigned_list,
+        );
+        let card = preceded(space0, pair(header(id), numbers));
+        let (id, (winners, numbers)) = aoc_parse::parse(input, card).map_err(|e| {
+            match (e.expected, e.context().map(|c| c.label)) {
+                (_, Some("id")) => ParseError::InvalidId {
+                    location: e.location(input),
+                    text: e.token().to_string(),
+                },
+                (Expected::Number, _) => ParseError::InvalidNumber {
+                    location: e.location(input),
+                    text: e.token().to_string(),
+                },
+                _ => ParseError::InvalidCard {
+                    location: Location::of(input, input.trim_start()),
+                    text: input.trim().to_string(),
+                },
+            }
+        })?;
+        Card::with_numbers(id, winners, numbers).ok_or_else(|| ParseError::TooManyWins {
+            location: Location::of(input, input.trim_start()),
+            text: input.trim().to_string(),
+        })
+    }
 
+    /// Creates the card, working out how many of its numbers win and what it scores, or `None` if
+    /// its score doesn't fit in an `i32`
+    pub fn with_numbers(id: i32, winners: Vec<i32>, numbers: Vec<i32>) -> Option<Self> {
         let win_count = numbers.iter().filter(|x| winners.contains(x)).count() as i32;
         let score = match win_count {
             0 => 0,
-            1 => 1,
-            _ => 2 << (win_count - 2),
+            _ => 2i32.checked_pow(win_count as u32 - 1)?,
         };
 
-        Self {
+        Some(Self {
             id,
             winners,
             numbers,
             win_count,
             score,
-        }
+        })
     }
+}
 
-    fn parse_nums(captures: &regex::Captures<'_>, name: &str) -> Vec<i32> {
-        captures
-            .name(name)
-            .unwrap()
-            .as_str()
-            .split_ascii_whitespace()
-            .filter_map(|x| x.parse::<i32>().ok())
-            .collect()
+/// Lined up in columns like the input
+impl Display for Card {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let format = |numbers: &[i32]| numbers.iter().map(|n| format!("{:>2}", n)).join(" ");
+        write!(
+            f,
+            "Card {:>3}: {} | {}",
+            self.id,
+            format(&self.winners),
+            format(&self.numbers)
+        )
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::Part;
+    use proptest::prelude::*;
+
+    fn card_strategy() -> impl Strategy<Value = Card> {
+        (
+            1..1000i32,
+            proptest::collection::vec(1..100i32, 1..=10),
+            proptest::collection::vec(1..100i32, 1..=25),
+        )
+            .prop_filter_map("scores too much", |(id, winners, numbers)| {
+                Card::with_numbers(id, winners, numbers)
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn card_round_trip(card in card_strategy()) {
+            prop_assert_eq!(Card::new(&card.to_string()).unwrap(), card);
+        }
+
+        #[test]
+        fn score_doubles_with_each_win(card in card_strategy()) {
+            match card.win_count {
+                0 => prop_assert_eq!(card.score, 0),
+                wins => prop_assert_eq!(card.score, 1 << (wins - 1)),
+            }
+        }
+    }
 
     #[test]
     fn test_input() {
@@ -188,7 +268,11 @@ mod tests {
         let mut expected_total = 0;
         let mut actual_total = 0;
 
-        for (actual, expected) in input_text.lines().map(Card::new).zip(expected) {
+        for (actual, expected) in input_text
+            .lines()
+            .map(|l| Card::new(l).unwrap())
+            .zip(expected)
+        {
             expected_total += expected.score;
             actual_total += actual.score;
             assert_eq!(actual, expected);
@@ -197,13 +281,56 @@ mod tests {
     }
 
     #[test]
-    fn test_a() {
+    fn test_parse_errors() {
         let input_text = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
-                                Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
-                                Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
-                                Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
-                                Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
-                                Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 2l 14  1";
+        assert_eq!(
+            CardManager::new(input_text).unwrap_err(),
+            ParseError::InvalidNumber {
+                location: Location::new(3, 41),
+                text: "2l".to_string(),
+            }
+        );
+        assert_eq!(
+            Card::new("Card 4: 41 92 73 84 69").unwrap_err(),
+            ParseError::InvalidCard {
+                location: Location::new(1, 1),
+                text: "Card 4: 41 92 73 84 69".to_string(),
+            }
+        );
+        assert_eq!(
+            Card::new("Card : 41 92 73 84 69 | 59 84").unwrap_err(),
+            ParseError::InvalidId {
+                location: Location::new(1, 6),
+                text: "".to_string(),
+            }
+        );
+
+        let input_text = "Card 1: 41 48 | 41 17
+Card 2: 13 32 | 61 32";
+        assert_eq!(
+            CardManager::new(input_text).unwrap_err(),
+            ParseError::WinsMissingCards {
+                location: Location::new(2, 1),
+                text: "Card 2: 13 32 | 61 32".to_string(),
+            }
+        );
+
+        let numbers = (1..=32).map(|n| n.to_string()).join(" ");
+        let input_text = format!("Card 1: {} | {}", numbers, numbers);
+        assert_eq!(
+            Card::new(&input_text).unwrap_err(),
+            ParseError::TooManyWins {
+                location: Location::new(1, 1),
+                text: input_text.clone(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_a() {
+        let input_text = EXAMPLES.input("example");
 
         let expected = [
             Card {
@@ -253,7 +380,11 @@ mod tests {
         let mut expected_total = 0;
         let mut actual_total = 0;
 
-        for (actual, expected) in input_text.lines().map(Card::new).zip(expected) {
+        for (actual, expected) in input_text
+            .lines()
+            .map(|l| Card::new(l).unwrap())
+            .zip(expected)
+        {
             expected_total += expected.score;
             actual_total += actual.score;
             assert_eq!(actual, expected);
@@ -263,14 +394,9 @@ mod tests {
 
     #[test]
     fn test_b() {
-        let input_text = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
-                                Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
-                                Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
-                                Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
-                                Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
-                                Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
-        let mut card_manager = CardManager::new(input_text);
-        let expected = 30;
+        let input_text = EXAMPLES.input("example");
+        let mut card_manager = CardManager::new(input_text).unwrap();
+        let expected: usize = EXAMPLES.answer("example", Part::Two);
 
         let mut total_cards = vec![];
         let mut loop_count = 0;
@@ -314,28 +440,18 @@ mod tests {
 
     #[test]
     fn test_c() {
-        let input_text = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
-                                Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
-                                Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
-                                Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
-                                Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
-                                Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
-        let mut card_manager = CardManager::new(input_text);
-        let expected = 30;
+        let input_text = EXAMPLES.input("example");
+        let mut card_manager = CardManager::new(input_text).unwrap();
+        let expected: i32 = EXAMPLES.answer("example", Part::Two);
 
         assert_eq!(expected, card_manager.play_all_rounds());
     }
 
     #[test]
     fn test_d() {
-        let input_text = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
-                                Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
-                                Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
-                                Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
-                                Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
-                                Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
-        let card_manager = CardManager::new(input_text);
-        let expected = 30;
+        let input_text = EXAMPLES.input("example");
+        let card_manager = CardManager::new(input_text).unwrap();
+        let expected: i32 = EXAMPLES.answer("example", Part::Two);
 
         assert_eq!(expected, card_manager.waterfall_rounds());
     }
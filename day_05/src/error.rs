@@ -0,0 +1,18 @@
+use aoc_core::Location;
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ParseError {
+    #[error("{location}: expected a '{text}' section after here")]
+    MissingSection { location: Location, text: String },
+    #[error("{location}: expected the seeds after 'seeds:'")]
+    MissingSeeds { location: Location },
+    #[error("{location}: '{text}' is not a valid seed")]
+    InvalidSeed { location: Location, text: String },
+    #[error("{location}: '{text}' is a seed without a length to make a range of")]
+    UnpairedSeed { location: Location, text: String },
+    #[error("{location}: '{text}' is not a range of at least one seed that ends within a u32")]
+    InvalidSeedRange { location: Location, text: String },
+    #[error("{location}: '{text}' is not a `<destination> <source> <length>` range")]
+    InvalidRange { location: Location, text: String },
+}
@@ -0,0 +1,79 @@
+//! Made up almanacs that look like the real ones
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+use crate::HEADERS;
+
+/// The number of seed ranges in the real inputs
+pub const SIZE: usize = 10;
+
+/// Every map rearranges the numbers below this, like the real inputs which use most of a `u32`
+const SPAN: u32 = 4_000_000_000;
+/// The real seed ranges are up to hundreds of millions long
+const MAX_SEED_RANGE: u32 = 400_000_000;
+
+/// Makes up an almanac of `size` seed ranges, where each map cuts `0..SPAN` into pieces and
+/// shuffles them around, so every number maps to exactly one other
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let seeds: Vec<String> = (0..size)
+        .flat_map(|_| {
+            let length = rng.gen_range(1..=MAX_SEED_RANGE);
+            let start = rng.gen_range(0..=SPAN - length);
+            [start.to_string(), length.to_string()]
+        })
+        .collect();
+    let mut text = format!("{}: {}\n", HEADERS[0], seeds.join(" "));
+
+    for header in HEADERS[1..].iter() {
+        let pieces = rng.gen_range(size..=size * 4).max(1);
+        let mut cuts: Vec<u32> = (1..pieces).map(|_| rng.gen_range(1..SPAN)).collect();
+        cuts.extend([0, SPAN]);
+        cuts.sort_unstable();
+        cuts.dedup();
+        let sources: Vec<(u32, u32)> = cuts.windows(2).map(|w| (w[0], w[1] - w[0])).collect();
+
+        let mut order: Vec<usize> = (0..sources.len()).collect();
+        order.shuffle(&mut rng);
+        let mut dest = 0;
+        let mut ranges = Vec::new();
+        for i in order {
+            let (source, length) = sources[i];
+            ranges.push(format!("{} {} {}", dest, source, length));
+            dest += length;
+        }
+        ranges.shuffle(&mut rng);
+        text += &format!("\n{}:\n{}\n", header, ranges.join("\n"));
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Almanac, Day05};
+    use aoc_core::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(7, 3), generate(7, 3));
+        assert_ne!(generate(7, 3), generate(8, 3));
+
+        let input_text = generate(7, 3);
+        let day = Day05::parse(&input_text).unwrap();
+        assert!(day.part_one() < SPAN);
+        assert!(day.part_two() < SPAN);
+
+        // Every number below the span still has to map to a different number below it
+        let almanac = Almanac::new(&generate(7, 1)).unwrap();
+        let mut locations: Vec<u32> = (0..SPAN)
+            .step_by(9_999_991)
+            .map(|n| almanac.apply_maps(n))
+            .collect();
+        assert!(locations.iter().all(|location| *location < SPAN));
+        let count = locations.len();
+        locations.sort_unstable();
+        locations.dedup();
+        assert_eq!(locations.len(), count);
+    }
+}
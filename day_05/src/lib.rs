@@ -1,9 +1,46 @@
-use std::time::Instant;
+use std::fmt::Display;
 
+use aoc_core::{Location, Solution};
+use aoc_interval::{Interval, IntervalSet};
+use aoc_parse::{header, unsigned, unsigned_list};
 use itertools::Itertools;
-use regex::{Captures, RegexBuilder};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{char, digit1, line_ending, multispace0, space0, space1},
+    combinator::{cut, eof, map_opt, peek},
+    error::context,
+    multi::many0,
+    sequence::{pair, preceded, terminated, tuple},
+};
 
-#[derive(Debug)]
+mod error;
+pub use error::ParseError;
+
+/// The examples from the puzzle description, with their answers in `examples/answers.toml`
+pub const EXAMPLES: aoc_core::Examples = aoc_core::examples!["example"];
+
+pub mod generate;
+
+/// Made up inputs, for testing and benchmarking without the real ones
+pub const GENERATOR: aoc_core::Generator = aoc_core::Generator {
+    size: generate::SIZE,
+    generate: generate::generate,
+};
+
+/// Every section header in the order they show up in the almanac, without their colons
+const HEADERS: [&str; 8] = [
+    "seeds",
+    "seed-to-soil map",
+    "soil-to-fertilizer map",
+    "fertilizer-to-water map",
+    "water-to-light map",
+    "light-to-temperature map",
+    "temperature-to-humidity map",
+    "humidity-to-location map",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Range {
     dest_start: u32,
     source_start: u32,
@@ -12,18 +49,40 @@ pub struct Range {
 
 impl Range {
     pub fn new(input: &str) -> Option<Self> {
-        Range::parse(input.split_ascii_whitespace().collect_tuple())
+        aoc_parse::parse(input, Range::parse).ok()
     }
 
-    fn parse(input: Option<(&str, &str, &str)>) -> Option<Self> {
-        match input {
-            Some((a, b, c)) => Some(Self {
-                dest_start: a.parse().ok()?,
-                source_start: b.parse().ok()?,
-                length: c.parse().ok()?,
-            }),
-            None => None,
-        }
+    /// Parses the three numbers of a range on a line of their own, failing if either end of it
+    /// goes past `u32::MAX`
+    ///
+    /// Anything that starts with a number is taken to be a range, so the rest of the line being
+    /// wrong fails outright rather than ending the map there.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use day_05::Range;
+    /// assert!(Range::new("0 4294967295 1").is_some());
+    /// assert!(Range::new("0 4294967295 2").is_none());
+    /// assert!(Range::new("4294967295 0 2").is_none());
+    /// ```
+    fn parse(input: &str) -> aoc_parse::IResult<'_, Self> {
+        let numbers = tuple((
+            unsigned,
+            preceded(space1, unsigned),
+            preceded(space1, unsigned),
+        ));
+        let line = terminated(numbers, pair(space0, alt((line_ending, eof))));
+        let range = map_opt(line, |(dest_start, source_start, length)| {
+            let fits = |start: u32| start as u64 + length as u64 <= u32::MAX as u64 + 1;
+            let range = Self {
+                dest_start,
+                source_start,
+                length,
+            };
+            (fits(dest_start) && fits(source_start)).then_some(range)
+        });
+        context("range", preceded(peek(digit1), cut(range)))(input)
     }
 
     /// Returns the destination index of the given source index if it is in the range, otherwise None
@@ -46,11 +105,61 @@ impl Range {
     }
 
     pub fn is_in_range(&self, source_index: u32) -> bool {
-        source_index >= self.source_start && source_index < self.source_start + self.length
+        source_index >= self.source_start && source_index - self.source_start < self.length
+    }
+
+    /// Every source index in the range. It's `u64` since a range can end at `u32::MAX`, which
+    /// leaves its end just past it.
+    pub fn source(&self) -> Interval<u64> {
+        Interval::with_len(self.source_start as u64, self.length as u64)
+    }
+
+    /// How far the range moves each source index to get its destination index
+    pub fn offset(&self) -> i64 {
+        self.dest_start as i64 - self.source_start as i64
+    }
+}
+
+impl Display for Range {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} {}",
+            self.dest_start, self.source_start, self.length
+        )
     }
 }
 
 #[derive(Debug)]
+pub struct Day05 {
+    almanac: Almanac,
+}
+
+impl Solution for Day05 {
+    type PartOne = u32;
+    type PartTwo = u32;
+    type Error = ParseError;
+
+    fn parse(input_text: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            almanac: Almanac::new(input_text)?,
+        })
+    }
+
+    fn part_one(&self) -> u32 {
+        let locations = self.almanac.seeds_to_soil();
+        locations
+            .into_iter()
+            .min()
+            .expect("the parser only accepts almanacs with seeds")
+    }
+
+    fn part_two(&self) -> u32 {
+        self.almanac.seed_ranges_to_soil()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Almanac {
     seeds: Vec<u32>,
     seed2soil: Vec<Range>,
@@ -62,32 +171,74 @@ pub struct Almanac {
     hum2location: Vec<Range>,
 }
 
+/// The seeds, then each map under its header
+impl Display for Almanac {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", HEADERS[0], self.seeds.iter().join(" "))?;
+        for (header, ranges) in HEADERS[1..].iter().zip(self.maps()) {
+            write!(f, "\n\n{}:", header)?;
+            for range in ranges {
+                write!(f, "\n{}", range)?;
+            }
+        }
+        writeln!(f)
+    }
+}
+
 #[allow(unused_variables)]
 impl Almanac {
-    pub fn new(input: &str) -> Self {
-        let almanac_regex = RegexBuilder::new(
-            r"seeds: (?<seeds>[ \t\d]+)\s*seed-to-soil map:(?<seed2soil>.*)soil-to-fertilizer map:(?<soil2fertilizer>.*)fertilizer-to-water map:(?<fertilizer2water>.*)water-to-light map:(?<water2light>.*)light-to-temperature map:(?<light2temp>.*)temperature-to-humidity map:(?<temp2humidity>.*)humidity-to-location map:(?<hum2location>.*)",
-        )
-        .dot_matches_new_line(true)
-        .build()
-        .unwrap();
-
-        let captures = almanac_regex.captures(input);
-        let captures = match captures {
-            None => panic!("Failed to parse almanac"),
-            Some(captures) => captures,
-        };
-
-        let seeds: Vec<u32> = Almanac::parse_seeds(&captures);
-        let seed2soil: Vec<Range> = Almanac::parse(&captures, "seed2soil");
-        let soil2fertilizer: Vec<Range> = Almanac::parse(&captures, "soil2fertilizer");
-        let fertilizer2water: Vec<Range> = Almanac::parse(&captures, "fertilizer2water");
-        let water2light: Vec<Range> = Almanac::parse(&captures, "water2light");
-        let light2temp: Vec<Range> = Almanac::parse(&captures, "light2temp");
-        let temp2humidity: Vec<Range> = Almanac::parse(&captures, "temp2humidity");
-        let hum2location: Vec<Range> = Almanac::parse(&captures, "hum2location");
-
-        Self {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        // The seeds have to be on the same line as their header, so there's no mistaking an empty
+        // list for the first map
+        let seeds = preceded(
+            tuple((
+                multispace0,
+                context(HEADERS[0], pair(tag(HEADERS[0]), char(':'))),
+                space0,
+            )),
+            unsigned_list,
+        );
+        let maps = tuple((
+            Almanac::map(HEADERS[1]),
+            Almanac::map(HEADERS[2]),
+            Almanac::map(HEADERS[3]),
+            Almanac::map(HEADERS[4]),
+            Almanac::map(HEADERS[5]),
+            Almanac::map(HEADERS[6]),
+            Almanac::map(HEADERS[7]),
+        ));
+        // Anything left after the last map is a range of it that isn't right
+        let almanac = terminated(pair(seeds, maps), context("range", eof));
+
+        let (seeds, maps) = aoc_parse::parse(input, almanac).map_err(|e| match e.context() {
+            Some(range) if range.label == "range" => ParseError::InvalidRange {
+                location: range.location(input),
+                text: range.line().to_string(),
+            },
+            Some(section) => ParseError::MissingSection {
+                location: section.location(input),
+                text: format!("{}:", section.label),
+            },
+            None if e.token().is_empty() => ParseError::MissingSeeds {
+                location: e.location(input),
+            },
+            None => ParseError::InvalidSeed {
+                location: e.location(input),
+                text: e.token().to_string(),
+            },
+        })?;
+        let (
+            seed2soil,
+            soil2fertilizer,
+            fertilizer2water,
+            water2light,
+            light2temp,
+            temp2humidity,
+            hum2location,
+        ) = maps;
+        Almanac::check_seed_ranges(input, &seeds)?;
+
+        Ok(Self {
             seeds,
             seed2soil,
             soil2fertilizer,
@@ -96,54 +247,90 @@ impl Almanac {
             light2temp,
             temp2humidity,
             hum2location,
-        }
+        })
     }
 
-    pub fn seed_ranges_to_soil(&mut self) -> u32 {
-        let start_time = Instant::now();
-        let mut lowest = u32::MAX;
-        let max = self.seeds.len() / 2;
-        for i in 0..max {
-            let start = self.seeds[i * 2];
-            let length = self.seeds[i * 2 + 1];
-            // println!("{} -> {}", start, length);
-            println!(
-                "Starting {} of {} | {} -> {}",
-                i,
-                max,
-                start,
-                start + length
-            );
-            for j in 0..length {
-                if length > 500 && (j % (length / 500) == 0) {
-                    println!(
-                        "Set #{}: {:.1}% ({} of {}) {:?} secs",
-                        i + 1,
-                        (j as f64 / length as f64 * 100.0),
-                        j,
-                        length,
-                        Instant::now().duration_since(start_time)
-                    );
-                }
-                let val = self.apply_maps(start + j);
-                lowest = std::cmp::min(val, lowest);
+    /// Checks the seeds pair up into ranges that each hold a seed and end by `u32::MAX`, for part
+    /// two to read them as
+    fn check_seed_ranges(input: &str, seeds: &[u32]) -> Result<(), ParseError> {
+        // The seeds were all parsed from the one line after `seeds:`
+        let line = input.trim_start().lines().next().unwrap_or_default();
+        let numbers = line.split_once(':').map_or("", |(_, numbers)| numbers);
+        let words = numbers.split_whitespace().collect_vec();
+        if let [.., unpaired] = words[..] {
+            if words.len() % 2 == 1 {
+                return Err(ParseError::UnpairedSeed {
+                    location: Location::of(input, unpaired),
+                    text: unpaired.to_string(),
+                });
             }
-            println!("Completed {} of {}", i + 1, max);
         }
-        lowest
+
+        let ranges = seeds.chunks_exact(2).zip(words.chunks_exact(2));
+        for (range, words) in ranges {
+            let (start, length) = (range[0] as u64, range[1] as u64);
+            if length == 0 || start + length > u32::MAX as u64 + 1 {
+                return Err(ParseError::InvalidSeedRange {
+                    location: Location::of(input, words[0]),
+                    text: words.join(" "),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Parses the map under the `name` header, which can have no ranges at all
+    fn map<'a>(name: &'static str) -> impl FnMut(&'a str) -> aoc_parse::IResult<'a, Vec<Range>> {
+        preceded(
+            pair(multispace0, context(name, header(tag(name)))),
+            many0(terminated(Range::parse, multispace0)),
+        )
     }
 
-    pub fn seeds_to_soil(&mut self) -> Vec<u32> {
-        let val = &self
+    /// Maps every seed in the seed ranges at once, splitting the ranges up wherever the maps do,
+    /// and gives the lowest location any of them end up at
+    pub fn seed_ranges_to_soil(&self) -> u32 {
+        let seeds: IntervalSet<u64> = self
             .seeds
-            .clone() //Need to clone in order to mutate
-            .iter()
-            .map(|s| self.apply_maps(*s))
-            .collect_vec();
-        val.clone()
+            .chunks_exact(2)
+            .map(|pair| Interval::with_len(pair[0] as u64, pair[1] as u64))
+            .collect();
+        let locations = self.maps().iter().fold(seeds, |numbers, ranges| {
+            let mapped = Almanac::apply_map_to_set(&numbers, ranges);
+            log::debug!(
+                "{} numbers in {} intervals",
+                mapped.len(),
+                mapped.intervals().len()
+            );
+            mapped
+        });
+        // Every seed range and map stays within a `u32`, so everything mapped does too
+        let lowest = locations
+            .min()
+            .expect("the parser only accepts seed ranges that hold seeds");
+        lowest
+            .try_into()
+            .expect("the parser only accepts seed ranges and maps within a u32")
+    }
+
+    pub fn seeds_to_soil(&self) -> Vec<u32> {
+        self.seeds.iter().map(|s| self.apply_maps(*s)).collect_vec()
+    }
+
+    /// Every map in the order they're applied
+    fn maps(&self) -> [&[Range]; 7] {
+        [
+            &self.seed2soil,
+            &self.soil2fertilizer,
+            &self.fertilizer2water,
+            &self.water2light,
+            &self.light2temp,
+            &self.temp2humidity,
+            &self.hum2location,
+        ]
     }
 
-    fn apply_maps(&mut self, seed: u32) -> u32 {
+    fn apply_maps(&self, seed: u32) -> u32 {
         let output = Almanac::apply_map(seed, &self.seed2soil);
         let output = Almanac::apply_map(output, &self.soil2fertilizer);
         let output = Almanac::apply_map(output, &self.fertilizer2water);
@@ -174,73 +361,156 @@ impl Almanac {
             .unwrap_or(input)
     }
 
-    pub fn parse_seeds(captures: &Captures<'_>) -> Vec<u32> {
-        match captures.name("seeds") {
-            Some(some) => some,
-            None => {
-                println!("No seeds");
-                return vec![];
-            }
-        }
-        .as_str()
-        .split_ascii_whitespace()
-        .filter_map(|x| x.parse::<u32>().ok())
-        .collect::<Vec<u32>>()
-    }
-
-    pub fn parse(captures: &Captures<'_>, name: &str) -> Vec<Range> {
-        let lines = match captures.name(name) {
-            Some(some) => some,
-            None => return vec![],
+    /// Applies the map to every number in the set, where the first range that holds a number is
+    /// the one that maps it
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use day_05::{Almanac, Range};
+    /// use aoc_interval::{Interval, IntervalSet};
+    /// let ranges = vec![Range::new("50 98 2").unwrap()];
+    /// let numbers = IntervalSet::from(Interval::new(97, 101));
+    /// assert_eq!(
+    ///     Almanac::apply_map_to_set(&numbers, &ranges).intervals(),
+    ///     &[Interval::new(50, 52), Interval::new(97, 98), Interval::new(100, 101)]
+    /// );
+    /// ```
+    pub fn apply_map_to_set(numbers: &IntervalSet<u64>, ranges: &[Range]) -> IntervalSet<u64> {
+        let mut unmapped = numbers.clone();
+        let mut mapped = IntervalSet::new();
+        for range in ranges {
+            let source = IntervalSet::from(range.source());
+            mapped = mapped.union(&unmapped.intersection(&source).shift(range.offset()));
+            unmapped = unmapped.difference(&source);
         }
-        .as_str();
-
-        lines.lines().filter_map(Range::new).collect_vec()
+        mapped.union(&unmapped)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{Location, Part};
+    use proptest::prelude::*;
+
+    /// Ranges anywhere within `u32`, as long as both of their ends fit too
+    fn range_strategy() -> impl Strategy<Value = Range> {
+        (any::<u32>(), any::<u32>())
+            .prop_flat_map(|(dest_start, source_start)| {
+                let room = u32::MAX - dest_start.max(source_start);
+                (
+                    Just(dest_start),
+                    Just(source_start),
+                    0..=room.saturating_add(1),
+                )
+            })
+            .prop_map(|(dest_start, source_start, length)| Range {
+                dest_start,
+                source_start,
+                length,
+            })
+    }
+
+    /// A seed range's start and length, holding at least one seed and ending within `u32`
+    fn seed_range_strategy() -> impl Strategy<Value = Vec<u32>> {
+        any::<u32>()
+            .prop_flat_map(|start| (Just(start), 1..=(u32::MAX - start).saturating_add(1)))
+            .prop_map(|(start, length)| vec![start, length])
+    }
+
+    fn ranges_strategy() -> impl Strategy<Value = Vec<Range>> {
+        proptest::collection::vec(range_strategy(), 0..5)
+    }
+
+    fn almanac_strategy() -> impl Strategy<Value = Almanac> {
+        (
+            proptest::collection::vec(seed_range_strategy(), 1..5).prop_map(|ranges| ranges.concat()),
+            [
+                ranges_strategy(),
+                ranges_strategy(),
+                ranges_strategy(),
+                ranges_strategy(),
+                ranges_strategy(),
+                ranges_strategy(),
+                ranges_strategy(),
+            ],
+        )
+            .prop_map(|(seeds, maps)| {
+                let [seed2soil, soil2fertilizer, fertilizer2water, water2light, light2temp, temp2humidity, hum2location] =
+                    maps;
+                Almanac {
+                    seeds,
+                    seed2soil,
+                    soil2fertilizer,
+                    fertilizer2water,
+                    water2light,
+                    light2temp,
+                    temp2humidity,
+                    hum2location,
+                }
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn almanac_round_trip(almanac in almanac_strategy()) {
+            prop_assert_eq!(Almanac::new(&almanac.to_string()).unwrap(), almanac);
+        }
+
+        #[test]
+        fn range_never_overflows(
+            dest_start in any::<u32>(),
+            source_start in any::<u32>(),
+            length in any::<u32>(),
+            input in any::<u32>(),
+        ) {
+            if let Some(range) = Range::new(&format!("{} {} {}", dest_start, source_start, length)) {
+                prop_assert_eq!(range.get_dest_index(input).is_some(), range.is_in_range(input));
+            }
+        }
+
+        #[test]
+        fn apply_map_outside_ranges(ranges in ranges_strategy(), input in any::<u32>()) {
+            prop_assume!(!ranges.iter().any(|range| range.is_in_range(input)));
+            prop_assert_eq!(Almanac::apply_map(input, &ranges), input);
+        }
+
+        #[test]
+        fn apply_map_inside_range(ranges in ranges_strategy(), range in range_strategy(), offset in any::<u32>()) {
+            // The first range that holds the input is the one that maps it
+            prop_assume!(range.length > 0);
+            let input = range.source_start + offset % range.length;
+            let mut ranges = ranges;
+            ranges.insert(0, range.clone());
+            prop_assert_eq!(
+                Almanac::apply_map(input, &ranges),
+                input - range.source_start + range.dest_start
+            );
+        }
+
+        #[test]
+        fn apply_map_to_set_agrees(
+            ranges in ranges_strategy(),
+            start in any::<u32>(),
+            length in 1..1_000_000u32,
+            offset in any::<u32>(),
+        ) {
+            // Every number is mapped the same as it would be on its own, without gaining any
+            let numbers = IntervalSet::from(Interval::with_len(start as u64, length as u64));
+            let mapped = Almanac::apply_map_to_set(&numbers, &ranges);
+            let input = start.saturating_add(offset % length);
+            prop_assert!(mapped.contains(Almanac::apply_map(input, &ranges) as u64));
+            prop_assert!(mapped.len() <= numbers.len());
+        }
+    }
 
     #[test]
     fn test_a() {
-        let input_text = "seeds: 79 14 55 13
+        let input_text = EXAMPLES.input("example");
+        let expected: u32 = EXAMPLES.answer("example", Part::One);
 
-        seed-to-soil map:
-        50 98 2
-        52 50 48
-        
-        soil-to-fertilizer map:
-        0 15 37
-        37 52 2
-        39 0 15
-        
-        fertilizer-to-water map:
-        49 53 8
-        0 11 42
-        42 0 7
-        57 7 4
-        
-        water-to-light map:
-        88 18 7
-        18 25 70
-        
-        light-to-temperature map:
-        45 77 23
-        81 45 19
-        68 64 13
-        
-        temperature-to-humidity map:
-        0 69 1
-        1 0 69
-        
-        humidity-to-location map:
-        60 56 37
-        56 93 4";
-        let expected = 35;
-
-        let mut almanac = Almanac::new(input_text);
+        let almanac = Almanac::new(input_text).unwrap();
         for (a, b) in almanac.seeds.iter().zip([79, 14, 55, 13].iter()) {
             assert_eq!(a, b);
         }
@@ -252,43 +522,102 @@ mod tests {
     }
 
     #[test]
-    fn test_b() {
+    fn test_parse_errors() {
         let input_text = "seeds: 79 14 55 13
 
         seed-to-soil map:
         50 98 2
         52 50 48
-        
+
+        soil-to-fertilizer map:
+        0 15 37";
+        assert_eq!(
+            Almanac::new(input_text).unwrap_err(),
+            ParseError::MissingSection {
+                location: Location::new(8, 16),
+                text: "fertilizer-to-water map:".to_string(),
+            }
+        );
+
+        let input_text = "seeds: 79 14 55 13
+        seed-to-soil map:
+        50 98 2
+        52 50
         soil-to-fertilizer map:
-        0 15 37
-        37 52 2
-        39 0 15
-        
         fertilizer-to-water map:
-        49 53 8
-        0 11 42
-        42 0 7
-        57 7 4
-        
         water-to-light map:
-        88 18 7
-        18 25 70
-        
         light-to-temperature map:
-        45 77 23
-        81 45 19
-        68 64 13
-        
         temperature-to-humidity map:
-        0 69 1
-        1 0 69
-        
-        humidity-to-location map:
-        60 56 37
-        56 93 4";
-        let expected = 46;
-
-        let mut almanac = Almanac::new(input_text);
+        humidity-to-location map:";
+        assert_eq!(
+            Almanac::new(input_text).unwrap_err(),
+            ParseError::InvalidRange {
+                location: Location::new(4, 9),
+                text: "52 50".to_string(),
+            }
+        );
+
+        let leftover = input_text.replace("52 50", "52 50 2") + "\n        0 1 2 oops";
+        assert_eq!(
+            Almanac::new(&leftover).unwrap_err(),
+            ParseError::InvalidRange {
+                location: Location::new(11, 9),
+                text: "0 1 2 oops".to_string(),
+            }
+        );
+
+        let no_seeds = input_text.replace("79 14 55 13", "");
+        assert_eq!(
+            Almanac::new(&no_seeds).unwrap_err(),
+            ParseError::MissingSeeds {
+                location: Location::new(1, 8),
+            }
+        );
+
+        // Part two reads the seeds in pairs, so they have to pair up into ranges of seeds
+        let odd_seeds = EXAMPLES.input("example").replace("79 14 55 13", "79 14 55");
+        assert_eq!(
+            Almanac::new(&odd_seeds).unwrap_err(),
+            ParseError::UnpairedSeed {
+                location: Location::new(1, 14),
+                text: "55".to_string(),
+            }
+        );
+
+        let empty_range = EXAMPLES.input("example").replace("55 13", "55 0");
+        assert_eq!(
+            Almanac::new(&empty_range).unwrap_err(),
+            ParseError::InvalidSeedRange {
+                location: Location::new(1, 14),
+                text: "55 0".to_string(),
+            }
+        );
+
+        let past_u32 = EXAMPLES.input("example").replace("79 14", "4294967290 7");
+        assert_eq!(
+            Almanac::new(&past_u32).unwrap_err(),
+            ParseError::InvalidSeedRange {
+                location: Location::new(1, 8),
+                text: "4294967290 7".to_string(),
+            }
+        );
+
+        let input_text = input_text.replace("55 13", "5S 13");
+        assert_eq!(
+            Almanac::new(&input_text).unwrap_err(),
+            ParseError::InvalidSeed {
+                location: Location::new(1, 14),
+                text: "5S".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_b() {
+        let input_text = EXAMPLES.input("example");
+        let expected: u32 = EXAMPLES.answer("example", Part::Two);
+
+        let almanac = Almanac::new(input_text).unwrap();
         let actual = almanac.seed_ranges_to_soil();
         println!("Got result of {}", actual);
 
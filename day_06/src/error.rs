@@ -0,0 +1,16 @@
+use aoc_core::Location;
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ParseError {
+    #[error("{location}: expected a '{text}' record")]
+    MissingRecord { location: Location, text: String },
+    #[error("{location}: '{text}' is not a valid number")]
+    InvalidNumber { location: Location, text: String },
+    #[error("{location}: '{text}' should have {times} distances, one for each time")]
+    MismatchedRaces {
+        location: Location,
+        text: String,
+        times: usize,
+    },
+}
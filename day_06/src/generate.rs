@@ -0,0 +1,45 @@
+//! Made up race records that look like the real ones
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// The number of races in the real inputs
+pub const SIZE: usize = 4;
+
+/// Makes up records for up to 4 races, as any more overflows the number of ways to win part two.
+/// Each race lasts a 2 digit number of milliseconds, with a record that's beatable but not by much.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let (times, distances): (Vec<u64>, Vec<u64>) = (0..size.clamp(1, SIZE))
+        .map(|_| {
+            let time = rng.gen_range(30..100);
+            let best = time * time / 4;
+            (time, rng.gen_range(best * 3 / 5..best * 4 / 5))
+        })
+        .unzip();
+
+    let line = |numbers: Vec<u64>| {
+        numbers
+            .iter()
+            .map(|n| format!("{:>6}", n))
+            .collect::<String>()
+    };
+    format!("Time:   {}\nDistance:{}\n", line(times), line(distances))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day06;
+    use aoc_core::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(7, 4), generate(7, 4));
+        assert_ne!(generate(7, 4), generate(8, 4));
+        assert_eq!(generate(7, 10), generate(7, 4));
+
+        let day = Day06::parse(&generate(7, 4)).unwrap();
+        assert!(day.part_one() > 0);
+        assert!(day.part_two() > 0);
+    }
+}
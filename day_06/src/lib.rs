@@ -1,6 +1,56 @@
+use aoc_core::{Location, Solution};
+use aoc_parse::{header, unsigned_list};
 use derive_new::new;
-use itertools::Itertools;
-use regex::RegexBuilder;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{line_ending, multispace0, space0},
+    combinator::{consumed, eof},
+    error::context,
+    sequence::{pair, preceded, terminated},
+};
+
+mod error;
+pub use error::ParseError;
+
+/// The examples from the puzzle description, with their answers in `examples/answers.toml`
+pub const EXAMPLES: aoc_core::Examples = aoc_core::examples!["example"];
+
+pub mod generate;
+
+/// Made up inputs, for testing and benchmarking without the real ones
+pub const GENERATOR: aoc_core::Generator = aoc_core::Generator {
+    size: generate::SIZE,
+    generate: generate::generate,
+};
+
+#[derive(Debug)]
+pub struct Day06 {
+    races: RaceSet,
+    single_race: RaceSet,
+}
+
+impl Solution for Day06 {
+    type PartOne = u32;
+    type PartTwo = u32;
+    type Error = ParseError;
+
+    fn parse(input_text: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            races: RaceSet::new(input_text)?,
+            single_race: RaceSet::new_part_2(input_text)?,
+        })
+    }
+
+    fn part_one(&self) -> u32 {
+        self.races.get_output()
+    }
+
+    /// The spaces between the numbers are just bad kerning, so it's really one long race
+    fn part_two(&self) -> u32 {
+        self.single_race.get_output()
+    }
+}
 
 #[derive(Debug, new)]
 pub struct Race {
@@ -9,8 +59,13 @@ pub struct Race {
 }
 
 impl Race {
+    /// The first and last time to hold the button for that wins the race, where the last comes
+    /// before the first if it can't be won
     pub fn get_winning_range(&self) -> (u32, u32) {
-        let quadratic_inner = f64::sqrt(self.time * self.time - 4.0 * self.distance);
+        // A race that can't be won has no roots, which clamping turns into an empty range around
+        // the peak
+        let discriminant = self.time * self.time - 4.0 * self.distance;
+        let quadratic_inner = f64::sqrt(discriminant.max(0.0));
         (
             f64::ceil((-self.time + quadratic_inner) / -2.0 + 0.00001) as u32,
             f64::floor((-self.time - quadratic_inner) / -2.0 - 0.00001) as u32,
@@ -19,71 +74,75 @@ impl Race {
 
     pub fn get_win_count(&self) -> u32 {
         let range = self.get_winning_range();
-        println!("Winning range: {:?}", range);
-        let winning_count = range.1 + 1 - range.0;
-        println!("Winning count: {}", winning_count);
+        log::trace!("Winning range: {:?}", range);
+        let winning_count = match range.1 < range.0 {
+            true => 0,
+            false => range.1 + 1 - range.0,
+        };
+        log::trace!("Winning count: {}", winning_count);
         winning_count
     }
 }
 
+/// The times, and the distance record with its distances
+type Records<'a> = (Vec<u64>, (&'a str, Vec<u64>));
+
 #[derive(Debug)]
 pub struct RaceSet {
     races: Vec<Race>,
 }
 
 impl RaceSet {
-    pub fn new(_input_text: &str) -> Self {
-        let re =
-            RegexBuilder::new(r"Time:\s*(?<Time>(\d+\s*)+)[\s]*Distance:\s*(?<Distance>(\d+\s*)+)")
-                .multi_line(true)
-                .build()
-                .unwrap();
-
-        let captures = re.captures(_input_text).unwrap();
-        let races = captures
-            .name("Time")
-            .unwrap()
-            .as_str()
-            .split_ascii_whitespace()
-            .zip(
-                captures
-                    .name("Distance")
-                    .unwrap()
-                    .as_str()
-                    .split_ascii_whitespace(),
-            )
-            .map(|(time, distance)| Race::new(time.parse().unwrap(), distance.parse().unwrap()))
+    pub fn new(input_text: &str) -> Result<Self, ParseError> {
+        let (times, (distance_record, distances)) = RaceSet::parse_records(input_text)?;
+        if times.len() != distances.len() {
+            let distance_record = distance_record.trim();
+            return Err(ParseError::MismatchedRaces {
+                location: Location::of(input_text, distance_record),
+                text: distance_record.to_string(),
+                times: times.len(),
+            });
+        }
+
+        let races = times
+            .into_iter()
+            .zip(distances)
+            .map(|(time, distance)| Race::new(time as f64, distance as f64))
             .collect();
-        Self { races }
+        Ok(Self { races })
     }
 
-    pub fn new_part_2(_input_text: &str) -> Self {
-        let re =
-            RegexBuilder::new(r"Time:\s*(?<Time>(\d+\s*)+)[\s]*Distance:\s*(?<Distance>(\d+\s*)+)")
-                .multi_line(true)
-                .build()
-                .unwrap();
-
-        let captures = re.captures(_input_text).unwrap();
-        let time = captures
-            .name("Time")
-            .unwrap()
-            .as_str()
-            .split_ascii_whitespace()
-            .join("")
-            .parse::<f64>()
-            .unwrap();
-        let distance = captures
-            .name("Distance")
-            .unwrap()
-            .as_str()
-            .split_ascii_whitespace()
-            .join("")
-            .parse::<f64>()
-            .unwrap();
-        Self {
+    pub fn new_part_2(input_text: &str) -> Result<Self, ParseError> {
+        let (times, (_, distances)) = RaceSet::parse_records(input_text)?;
+        let join = |numbers: Vec<u64>| numbers.iter().map(u64::to_string).collect::<String>();
+        let time = join(times).parse::<f64>().unwrap_or_default();
+        let distance = join(distances).parse::<f64>().unwrap_or_default();
+        Ok(Self {
             races: vec![Race::new(time, distance)],
-        }
+        })
+    }
+
+    /// Parses the numbers of the `Time:` and `Distance:` records, along with the whole of the
+    /// distance record
+    fn parse_records(input_text: &str) -> Result<Records<'_>, ParseError> {
+        let record = |label| {
+            let numbers = terminated(unsigned_list, pair(space0, alt((line_ending, eof))));
+            preceded(
+                context(label, preceded(multispace0, header(tag(label)))),
+                numbers,
+            )
+        };
+        let records = pair(record("Time"), consumed(record("Distance")));
+        aoc_parse::parse(input_text, records).map_err(|e| match e.context() {
+            Some(record) => ParseError::MissingRecord {
+                location: e.location(input_text),
+                text: format!("{}:", record.label),
+            },
+            None => ParseError::InvalidNumber {
+                location: e.location(input_text),
+                text: e.token().to_string(),
+            },
+        })
     }
 
     pub fn get_output(&self) -> u32 {
@@ -94,14 +153,14 @@ impl RaceSet {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::Part;
 
     #[test]
     fn test_a() {
-        let input_text = "Time:      7  15   30
-        Distance:  9  40  200";
-        let expected = 288;
+        let input_text = EXAMPLES.input("example");
+        let expected: u32 = EXAMPLES.answer("example", Part::One);
 
-        let races = RaceSet::new(input_text);
+        let races = RaceSet::new(input_text).unwrap();
         let actual = races.get_output();
         println!("Got result of {}", actual);
 
@@ -109,12 +168,54 @@ mod tests {
     }
 
     #[test]
-    fn test_b() {
+    fn test_unwinnable() {
+        // At best these only tie the record, or fall short of it
+        for (time, distance) in [(2.0, 1.0), (5.0, 6.0), (1.0, 5.0), (0.0, 0.0)] {
+            assert_eq!(Race::new(time, distance).get_win_count(), 0);
+        }
+        let races = RaceSet::new("Time: 7 2\nDistance: 9 1").unwrap();
+        assert_eq!(races.get_output(), 0);
+    }
+
+    #[test]
+    fn test_parse_errors() {
         let input_text = "Time:      7  15   30
+        Distance:  9  40";
+        assert_eq!(
+            RaceSet::new(input_text).unwrap_err(),
+            ParseError::MismatchedRaces {
+                location: Location::new(2, 9),
+                text: "Distance:  9  40".to_string(),
+                times: 3,
+            }
+        );
+
+        let input_text = "Time:      7  1S   30
         Distance:  9  40  200";
-        let expected = 71503;
+        assert_eq!(
+            RaceSet::new_part_2(input_text).unwrap_err(),
+            ParseError::InvalidNumber {
+                location: Location::new(1, 15),
+                text: "1S".to_string(),
+            }
+        );
+
+        let input_text = "Time:      7  15   30";
+        assert_eq!(
+            RaceSet::new(input_text).unwrap_err(),
+            ParseError::MissingRecord {
+                location: Location::new(1, 22),
+                text: "Distance:".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_b() {
+        let input_text = EXAMPLES.input("example");
+        let expected: u32 = EXAMPLES.answer("example", Part::Two);
 
-        let races = RaceSet::new_part_2(input_text);
+        let races = RaceSet::new_part_2(input_text).unwrap();
         let actual = races.get_output();
         println!("Got result of {}", actual);
 
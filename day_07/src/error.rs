@@ -0,0 +1,22 @@
+use aoc_core::{Located, Location};
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ParseError {
+    #[error("{location}: '{text}' is not a hand of 5 cards followed by a bid")]
+    InvalidHand { location: Location, text: String },
+    #[error("{location}: '{text}' is not a valid card")]
+    InvalidCard { location: Location, text: String },
+    #[error("{location}: '{text}' is not a valid bid")]
+    InvalidBid { location: Location, text: String },
+}
+
+impl Located for ParseError {
+    fn location_mut(&mut self) -> &mut Location {
+        match self {
+            ParseError::InvalidHand { location, .. }
+            | ParseError::InvalidCard { location, .. }
+            | ParseError::InvalidBid { location, .. } => location,
+        }
+    }
+}
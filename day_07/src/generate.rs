@@ -0,0 +1,66 @@
+//! Made up hands of Camel Cards that look like the real ones
+
+use rand::{
+    distributions::WeightedIndex, prelude::Distribution, rngs::StdRng, seq::SliceRandom, Rng,
+    SeedableRng,
+};
+
+/// The number of hands in the real inputs
+pub const SIZE: usize = 1000;
+
+const CARDS: [char; 13] = [
+    '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A',
+];
+
+/// How many of each card make up each type of hand, weighted by how often they show up in the
+/// real inputs, where pairs are much more common than they'd be by chance
+const TYPES: [(&[usize], u32); 7] = [
+    (&[5], 1),
+    (&[4, 1], 96),
+    (&[3, 2], 105),
+    (&[3, 1, 1], 174),
+    (&[2, 2, 1], 167),
+    (&[2, 1, 1, 1], 259),
+    (&[1, 1, 1, 1, 1], 198),
+];
+
+/// Makes up `size` hands along with bids of up to 1000
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let weights = WeightedIndex::new(TYPES.iter().map(|(_, weight)| weight)).unwrap();
+    let mut text = String::new();
+    for _ in 0..size {
+        let (groups, _) = TYPES[weights.sample(&mut rng)];
+        let mut hand: Vec<char> = CARDS
+            .choose_multiple(&mut rng, groups.len())
+            .zip(groups)
+            .flat_map(|(card, count)| std::iter::repeat_n(*card, *count))
+            .collect();
+        hand.shuffle(&mut rng);
+        text += &format!(
+            "{} {}\n",
+            hand.into_iter().collect::<String>(),
+            rng.gen_range(1..=1000)
+        );
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day07;
+    use aoc_core::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(7, 100), generate(7, 100));
+        assert_ne!(generate(7, 100), generate(8, 100));
+
+        let input_text = generate(7, 100);
+        assert_eq!(input_text.lines().count(), 100);
+        let day = Day07::parse(&input_text).unwrap();
+        assert!(day.part_one() > 0);
+        assert!(day.part_two() > 0);
+    }
+}
@@ -1,3 +1,230 @@
+use aoc_core::{Location, Solution};
+use aoc_parse::unsigned;
+use itertools::Itertools;
+use nom::{
+    bytes::complete::is_not,
+    character::complete::{space0, space1},
+    error::context,
+    sequence::{preceded, separated_pair},
+};
+
 pub mod part_one;
 
 pub mod part_two;
+
+#[cfg(test)]
+mod strategies;
+
+mod error;
+pub use error::ParseError;
+
+/// The examples from the puzzle description, with their answers in `examples/answers.toml`
+pub const EXAMPLES: aoc_core::Examples = aoc_core::examples!["example"];
+
+pub mod generate;
+
+/// Made up inputs, for testing and benchmarking without the real ones
+pub const GENERATOR: aoc_core::Generator = aoc_core::Generator {
+    size: generate::SIZE,
+    generate: generate::generate,
+};
+
+#[derive(Debug)]
+pub struct Day07 {
+    part_one: part_one::CardsManager,
+    part_two: part_two::CardsManager,
+}
+
+impl Solution for Day07 {
+    type PartOne = i32;
+    type PartTwo = i32;
+    type Error = ParseError;
+
+    fn parse(input_text: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            part_one: part_one::CardsManager::new(input_text)?,
+            part_two: part_two::CardsManager::new(input_text)?,
+        })
+    }
+
+    fn part_one(&self) -> i32 {
+        self.part_one.get_output()
+    }
+
+    fn part_two(&self) -> i32 {
+        self.part_two.get_output()
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum HandType {
+    FiveOfAKind,
+    FourOfAKind,
+    FullHouse,
+    ThreeOfAKind,
+    TwoPair,
+    OnePair,
+    HighCard,
+}
+
+impl HandType {
+    /// The type of a hand going by how many cards share its most common label, and how many
+    /// share the next most common
+    pub fn from_counts(most: i32, second: i32) -> HandType {
+        match (most, second) {
+            (5.., _) => HandType::FiveOfAKind,
+            (4, _) => HandType::FourOfAKind,
+            (3, 2..) => HandType::FullHouse,
+            (3, _) => HandType::ThreeOfAKind,
+            (2, 2) => HandType::TwoPair,
+            (2, _) => HandType::OnePair,
+            _ => HandType::HighCard,
+        }
+    }
+
+    pub fn get_rank(&self) -> i32 {
+        match self {
+            HandType::FiveOfAKind => 7,
+            HandType::FourOfAKind => 6,
+            HandType::FullHouse => 5,
+            HandType::ThreeOfAKind => 4,
+            HandType::TwoPair => 3,
+            HandType::OnePair => 2,
+            HandType::HighCard => 1,
+        }
+    }
+}
+
+impl PartialOrd for HandType {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for HandType {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.get_rank().cmp(&other.get_rank())
+    }
+}
+
+/// Parses a `<cards> <bid>` line, such as `32T3K 765`, reading the labels with `card` so each
+/// part can play by its own rules
+pub fn parse_hand<C>(
+    line: &str,
+    card: impl Fn(char) -> Option<C>,
+) -> Result<(Vec<C>, i32), ParseError> {
+    let hand = separated_pair(is_not(" \t\r\n"), space1, context("bid", unsigned));
+    let (cards, bid) =
+        aoc_parse::parse(line, preceded(space0, hand)).map_err(|e| match e.context() {
+            Some(_) => ParseError::InvalidBid {
+                location: e.location(line),
+                text: e.token().to_string(),
+            },
+            None => ParseError::InvalidHand {
+                location: Location::of(line, line.trim_start()),
+                text: line.trim().to_string(),
+            },
+        })?;
+    Ok((parse_cards(line, cards, card)?, bid))
+}
+
+/// Parses exactly five card labels, with `line` only used to locate any errors
+pub fn parse_cards<C>(
+    line: &str,
+    cards: &str,
+    card: impl Fn(char) -> Option<C>,
+) -> Result<Vec<C>, ParseError> {
+    let parsed: Vec<C> = cards
+        .char_indices()
+        .map(|(i, c)| {
+            card(c).ok_or_else(|| ParseError::InvalidCard {
+                location: Location::of(line, &cards[i..]),
+                text: c.to_string(),
+            })
+        })
+        .try_collect()?;
+    match parsed.len() {
+        5 => Ok(parsed),
+        _ => Err(ParseError::InvalidHand {
+            location: Location::of(line, cards),
+            text: cards.to_string(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use strategies::*;
+
+    proptest! {
+        #[test]
+        fn hand_round_trip(line in hand_line()) {
+            round_trip::<part_one::Hand>(&line)?;
+            round_trip::<part_two::Hand>(&line)?;
+        }
+
+        #[test]
+        fn hand_ordering_follows_rank(a in hand_line(), b in hand_line()) {
+            ordering_follows_rank::<part_one::Hand>(&a, &b)?;
+            ordering_follows_rank::<part_two::Hand>(&a, &b)?;
+        }
+
+        #[test]
+        fn hand_ordering_is_transitive(lines in proptest::collection::vec(hand_line(), 3)) {
+            ordering_is_transitive::<part_one::Hand>(&lines)?;
+            ordering_is_transitive::<part_two::Hand>(&lines)?;
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let input_text = "32T3K 765
+        T55J5 684
+        KK67 28";
+        assert_eq!(
+            Day07::parse(input_text).unwrap_err(),
+            ParseError::InvalidHand {
+                location: Location::new(3, 9),
+                text: "KK67".to_string(),
+            }
+        );
+
+        let input_text = "32T3K 765
+        T55X5 684";
+        assert_eq!(
+            Day07::parse(input_text).unwrap_err(),
+            ParseError::InvalidCard {
+                location: Location::new(2, 12),
+                text: "X".to_string(),
+            }
+        );
+
+        let input_text = "32T3K 76S";
+        assert_eq!(
+            Day07::parse(input_text).unwrap_err(),
+            ParseError::InvalidBid {
+                location: Location::new(1, 7),
+                text: "76S".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_rulesets_agree() {
+        // Both parts read the same line, so they fail in the same place
+        for line in ["  32T3K 765 12", "32T3X 765", "32T3K -1"] {
+            let one = parse_hand(line, part_one::Card::new).unwrap_err();
+            let two = parse_hand(line, part_two::Card::new).unwrap_err();
+            assert_eq!(one, two);
+        }
+        assert_eq!(
+            parse_hand("  32T3K 765 12", part_two::Card::new).unwrap_err(),
+            ParseError::InvalidHand {
+                location: Location::new(1, 3),
+                text: "32T3K 765 12".to_string(),
+            }
+        );
+    }
+}
@@ -1,8 +1,11 @@
+use aoc_core::Located;
 use itertools::Itertools;
-use regex::RegexBuilder;
-use std::{collections::HashMap, fmt::Debug};
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Display},
+};
 
-use HandType::*;
+use crate::{HandType, ParseError};
 
 #[derive(Ord, PartialOrd, Eq, PartialEq, Clone, Copy, Hash)]
 pub enum Card {
@@ -25,54 +28,29 @@ impl Debug for Card {
     }
 }
 
+/// The card's label, as it's written in the input
+impl Display for Card {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Card::Num(10) => write!(f, "T"),
+            card => write!(f, "{:?}", card),
+        }
+    }
+}
+
 impl Card {
-    pub fn new(c: char) -> Card {
-        match c {
+    /// Creates the card for the given label, or `None` if it isn't one
+    pub fn new(c: char) -> Option<Card> {
+        let card = match c {
             '2'..='9' => Card::Num(c.to_digit(10).unwrap() as i32),
             'T' => Card::Num(10),
             'J' => Card::Jack,
             'Q' => Card::Queen,
             'K' => Card::King,
             'A' => Card::Ace,
-            _ => panic!("Invalid card"),
-        }
-    }
-}
-
-#[derive(Debug, Eq, PartialEq)]
-pub enum HandType {
-    FiveOfAKind,
-    FourOfAKind,
-    FullHouse,
-    ThreeOfAKind,
-    TwoPair,
-    OnePair,
-    HighCard,
-}
-
-impl HandType {
-    pub fn get_rank(&self) -> i32 {
-        match self {
-            HandType::FiveOfAKind => 7,
-            HandType::FourOfAKind => 6,
-            HandType::FullHouse => 5,
-            HandType::ThreeOfAKind => 4,
-            HandType::TwoPair => 3,
-            HandType::OnePair => 2,
-            HandType::HighCard => 1,
-        }
-    }
-}
-
-impl PartialOrd for HandType {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
-impl Ord for HandType {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.get_rank().cmp(&other.get_rank())
+            _ => return None,
+        };
+        Some(card)
     }
 }
 
@@ -93,35 +71,48 @@ impl Hand {
         }
     }
 
+    /// Parses a `<cards> <bid>` line, such as `32T3K 765`
+    pub fn parse(line: &str) -> Result<Self, ParseError> {
+        let (cards, bid) = crate::parse_hand(line, Card::new)?;
+        Ok(Hand::new(cards, bid))
+    }
+
+    /// Get the HandType of the cards
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use day_07::{part_one::{Card, Hand}, HandType::*};
+    ///
+    /// let cards = |labels: &str| labels.chars().map(|c| Card::new(c).unwrap()).collect::<Vec<_>>();
+    /// assert_eq!(Hand::get_type(&cards("KK677")), TwoPair);
+    /// assert_eq!(Hand::get_type(&cards("AAAAAA")), FiveOfAKind);
+    /// assert_eq!(Hand::get_type(&cards("")), HighCard);
+    /// ```
     pub fn get_type(cards: &[Card]) -> HandType {
         let mut map = HashMap::new();
         for card in cards.iter() {
             *map.entry(card).or_insert(0) += 1;
         }
 
-        let duplicates = map
-            .into_iter()
-            .map(|(k, v)| (k, v))
-            .sorted_by(|a, b| Ord::cmp(&b.1, &a.1))
-            .collect_vec();
+        let mut counts = map.into_values().sorted().rev();
+        HandType::from_counts(counts.next().unwrap_or(0), counts.next().unwrap_or(0))
+    }
+}
 
-        // println!("Duplicates {:?}", dups);
-        match duplicates[..] {
-            [a] if (a.1 == 5) => FiveOfAKind,
-            [a, _] if (a.1 == 4) => FourOfAKind,
-            [a, b] if (a.1 == 3 && b.1 == 2) => FullHouse,
-            [a, ..] if (a.1 == 3) => ThreeOfAKind,
-            [a, b, _] if (a.1 == 2 && b.1 == 2) => TwoPair,
-            [a, b, _, _] if (a.1 == 2 && b.1 == 1) => OnePair,
-            [_, _, _, _, _] => HighCard,
-            _ => panic!("Invalid hand"),
+impl Display for Hand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for card in &self.cards {
+            write!(f, "{}", card)?;
         }
+        write!(f, " {}", self.bid)
     }
 }
 
+/// Hands are only equal when they rank the same, which takes the same cards in the same order
 impl PartialEq for Hand {
     fn eq(&self, other: &Self) -> bool {
-        self.hand_type == other.hand_type
+        self.cmp(other) == std::cmp::Ordering::Equal
     }
 }
 
@@ -156,33 +147,23 @@ pub struct CardsManager {
 }
 
 impl CardsManager {
-    pub fn new(input_text: &str) -> Self {
-        let re = RegexBuilder::new(r"(?<Hand>[\d\w]{5}) (?<Bid>[\d]+)\s*")
-            .multi_line(true)
-            .build()
-            .unwrap();
-        let matches = re.captures_iter(input_text);
-        let hands = matches
-            .map(|captures| {
-                let cards = captures
-                    .name("Hand")
-                    .unwrap()
-                    .as_str()
-                    .chars()
-                    .map(Card::new)
-                    .collect_vec();
-                let bid = captures.name("Bid").unwrap().as_str().parse().unwrap();
-                Hand::new(cards, bid)
-            })
-            .collect_vec();
+    pub fn new(input_text: &str) -> Result<Self, ParseError> {
+        let hands: Vec<Hand> = input_text
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| Hand::parse(line).map_err(|e| e.on_line(i + 1)))
+            .try_collect()?;
 
-        let hands_debug = hands.iter().map(|h| format!("{:?}", h)).join("\n");
-        println!(
-            "Got {} Hands: ===========\n{}\n===========",
-            hands.len(),
-            hands_debug
-        );
-        Self { hands }
+        if log::log_enabled!(log::Level::Trace) {
+            let hands_debug = hands.iter().map(|h| format!("{:?}", h)).join("\n");
+            log::trace!(
+                "Got {} Hands: ===========\n{}\n===========",
+                hands.len(),
+                hands_debug
+            );
+        }
+        Ok(Self { hands })
     }
 
     pub fn get_output(&self) -> i32 {
@@ -198,17 +179,26 @@ impl CardsManager {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::strategies::Rules;
+    use crate::EXAMPLES;
+    use aoc_core::Part;
+
+    impl Rules for Hand {
+        fn parse(line: &str) -> Result<Self, ParseError> {
+            Hand::parse(line)
+        }
+
+        fn rank(&self) -> i32 {
+            self.hand_type.get_rank()
+        }
+    }
 
     #[test]
     fn test_a() {
-        let input_text = "32T3K 765
-        T55J5 684
-        KK677 28
-        KTJJT 220
-        QQQJA 483";
-        let expected = 6440;
+        let input_text = EXAMPLES.input("example");
+        let expected: i32 = EXAMPLES.answer("example", Part::One);
 
-        let cards = CardsManager::new(input_text);
+        let cards = CardsManager::new(input_text).unwrap();
         let sorted_hands = cards.hands.iter().sorted().collect_vec();
         let hands_debug = sorted_hands
             .iter()
@@ -239,44 +229,44 @@ mod tests {
     fn test_hand_type() {
         let hand1 = Hand::new(
             vec![
-                Card::new('A'),
-                Card::new('A'),
-                Card::new('A'),
-                Card::new('A'),
-                Card::new('A'),
+                Card::new('A').unwrap(),
+                Card::new('A').unwrap(),
+                Card::new('A').unwrap(),
+                Card::new('A').unwrap(),
+                Card::new('A').unwrap(),
             ],
             0,
         );
         assert_eq!(hand1.hand_type, HandType::FiveOfAKind);
         let hand2 = Hand::new(
             vec![
-                Card::new('A'),
-                Card::new('Q'),
-                Card::new('A'),
-                Card::new('A'),
-                Card::new('A'),
+                Card::new('A').unwrap(),
+                Card::new('Q').unwrap(),
+                Card::new('A').unwrap(),
+                Card::new('A').unwrap(),
+                Card::new('A').unwrap(),
             ],
             0,
         );
         assert_eq!(hand2.hand_type, HandType::FourOfAKind);
         let hand3 = Hand::new(
             vec![
-                Card::new('3'),
-                Card::new('Q'),
-                Card::new('T'),
-                Card::new('3'),
-                Card::new('3'),
+                Card::new('3').unwrap(),
+                Card::new('Q').unwrap(),
+                Card::new('T').unwrap(),
+                Card::new('3').unwrap(),
+                Card::new('3').unwrap(),
             ],
             0,
         );
         assert_eq!(hand3.hand_type, HandType::ThreeOfAKind);
         let hand4 = Hand::new(
             vec![
-                Card::new('3'),
-                Card::new('T'),
-                Card::new('T'),
-                Card::new('3'),
-                Card::new('3'),
+                Card::new('3').unwrap(),
+                Card::new('T').unwrap(),
+                Card::new('T').unwrap(),
+                Card::new('3').unwrap(),
+                Card::new('3').unwrap(),
             ],
             0,
         );
@@ -1,8 +1,11 @@
+use aoc_core::Located;
 use itertools::Itertools;
-use regex::RegexBuilder;
-use std::{collections::HashMap, fmt::Debug, ops::Index};
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Display},
+};
 
-use HandType::*;
+use crate::{HandType, ParseError};
 
 #[derive(Ord, PartialOrd, Eq, PartialEq, Clone, Copy, Hash)]
 pub enum Card {
@@ -25,54 +28,29 @@ impl Debug for Card {
     }
 }
 
+/// The card's label, as it's written in the input
+impl Display for Card {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Card::Num(10) => write!(f, "T"),
+            card => write!(f, "{:?}", card),
+        }
+    }
+}
+
 impl Card {
-    pub fn new(c: char) -> Card {
-        match c {
+    /// Creates the card for the given label, or `None` if it isn't one
+    pub fn new(c: char) -> Option<Card> {
+        let card = match c {
             '2'..='9' => Card::Num(c.to_digit(10).unwrap() as i32),
             'T' => Card::Num(10),
             'J' => Card::Joker,
             'Q' => Card::Queen,
             'K' => Card::King,
             'A' => Card::Ace,
-            _ => panic!("Invalid card"),
-        }
-    }
-}
-
-#[derive(Debug, Eq, PartialEq)]
-pub enum HandType {
-    FiveOfAKind,
-    FourOfAKind,
-    FullHouse,
-    ThreeOfAKind,
-    TwoPair,
-    OnePair,
-    HighCard,
-}
-
-impl HandType {
-    pub fn get_rank(&self) -> i32 {
-        match self {
-            HandType::FiveOfAKind => 7,
-            HandType::FourOfAKind => 6,
-            HandType::FullHouse => 5,
-            HandType::ThreeOfAKind => 4,
-            HandType::TwoPair => 3,
-            HandType::OnePair => 2,
-            HandType::HighCard => 1,
-        }
-    }
-}
-
-impl PartialOrd for HandType {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
-impl Ord for HandType {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.get_rank().cmp(&other.get_rank())
+            _ => return None,
+        };
+        Some(card)
     }
 }
 
@@ -94,9 +72,15 @@ impl Hand {
         }
     }
 
-    pub fn from_string(input: &str) -> Self {
-        let cards = input.chars().map(Card::new).collect_vec();
-        Self::new(cards, 0)
+    /// Parses a `<cards> <bid>` line, such as `32T3K 765`
+    pub fn parse(line: &str) -> Result<Self, ParseError> {
+        let (cards, bid) = crate::parse_hand(line, Card::new)?;
+        Ok(Hand::new(cards, bid))
+    }
+
+    pub fn from_string(input: &str) -> Result<Self, ParseError> {
+        let cards = crate::parse_cards(input, input, Card::new)?;
+        Ok(Self::new(cards, 0))
     }
 
     /// Get the HandType of the hand
@@ -104,37 +88,31 @@ impl Hand {
     /// # Examples
     ///
     /// ```
-    /// use day_07::part_two::Hand;
-    /// use day_07::part_two::HandType::*;
+    /// use day_07::{part_two::{Card, Hand}, HandType::*};
     ///
-    /// let hand = Hand::from_string("JJJJT");
+    /// let hand = Hand::from_string("JJJJT").unwrap();
     /// assert_eq!(hand.hand_type, FiveOfAKind);
-    /// let hand = Hand::from_string("JJJJJ");
+    /// let hand = Hand::from_string("JJJJJ").unwrap();
     /// assert_eq!(hand.hand_type, FiveOfAKind);
-    /// let hand = Hand::from_string("JJJA2");
+    /// let hand = Hand::from_string("JJJA2").unwrap();
     /// assert_eq!(hand.hand_type, FourOfAKind);
+    ///
+    /// // Hands of other sizes still have a type, even if the puzzle never deals them
+    /// let cards = Hand::get_duplicates(&[Card::Joker, Card::Ace]);
+    /// assert_eq!(Hand::get_type(cards), OnePair);
+    /// assert_eq!(Hand::get_type(vec![]), HighCard);
     /// ```
     pub fn get_type(duplicates: Vec<(&Card, i32)>) -> HandType {
+        // The jokers always do best joining whichever label there's already the most of
         let joker_count = Hand::get_joker_count(&duplicates);
-        match joker_count {
-            0 => return Hand::get_type_no_jokers(&duplicates),
-            4 | 5 => return FiveOfAKind,
-            _ => (),
-        }
-
-        let mut duplicates = duplicates.clone();
-        duplicates.retain_mut(|d| d.0 != &Card::Joker);
-
-        // match duplicates.iter_mut().find(|d| d.0 != &Card::Joker) {
-        match duplicates.first_mut() {
-            Some(card) => {
-                println!("FNJ {:?} {}", card.0, card.1);
-                card.1 += joker_count;
-                println!("FNJ After {:?} {}", card.0, card.1);
-            }
-            None => return FiveOfAKind,
-        };
-        Hand::get_type_no_jokers(&duplicates)
+        let mut counts = duplicates
+            .iter()
+            .filter(|d| d.0 != &Card::Joker)
+            .map(|d| d.1)
+            .sorted()
+            .rev();
+        let most = counts.next().unwrap_or(0) + joker_count;
+        HandType::from_counts(most, counts.next().unwrap_or(0))
     }
 
     fn get_joker_count(duplicates: &[(&Card, i32)]) -> i32 {
@@ -147,19 +125,6 @@ impl Hand {
             .sum()
     }
 
-    fn get_type_no_jokers(duplicates: &[(&Card, i32)]) -> HandType {
-        match duplicates[..] {
-            [a] if (a.1 == 5) => FiveOfAKind,
-            [a, _] if (a.1 == 4) => FourOfAKind,
-            [a, b] if (a.1 == 3 && b.1 == 2) => FullHouse,
-            [a, ..] if (a.1 == 3) => ThreeOfAKind,
-            [a, b, _] if (a.1 == 2 && b.1 == 2) => TwoPair,
-            [a, b, _, _] if (a.1 == 2 && b.1 == 1) => OnePair,
-            [_, _, _, _, _] => HighCard,
-            _ => panic!("Invalid hand"),
-        }
-    }
-
     pub fn get_duplicates(cards: &[Card]) -> Vec<(&Card, i32)> {
         let mut map = HashMap::new();
         for card in cards.iter() {
@@ -167,15 +132,24 @@ impl Hand {
         }
 
         map.into_iter()
-            .map(|(k, v)| (k, v))
             .sorted_by(|a, b| Ord::cmp(&b.1, &a.1))
             .collect_vec()
     }
 }
 
+impl Display for Hand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for card in &self.cards {
+            write!(f, "{}", card)?;
+        }
+        write!(f, " {}", self.bid)
+    }
+}
+
+/// Hands are only equal when they rank the same, which takes the same cards in the same order
 impl PartialEq for Hand {
     fn eq(&self, other: &Self) -> bool {
-        self.hand_type == other.hand_type
+        self.cmp(other) == std::cmp::Ordering::Equal
     }
 }
 
@@ -210,33 +184,23 @@ pub struct CardsManager {
 }
 
 impl CardsManager {
-    pub fn new(input_text: &str) -> Self {
-        let re = RegexBuilder::new(r"(?<Hand>[\d\w]{5}) (?<Bid>[\d]+)\s*")
-            .multi_line(true)
-            .build()
-            .unwrap();
-        let matches = re.captures_iter(input_text);
-        let hands = matches
-            .map(|captures| {
-                let cards = captures
-                    .name("Hand")
-                    .unwrap()
-                    .as_str()
-                    .chars()
-                    .map(Card::new)
-                    .collect_vec();
-                let bid = captures.name("Bid").unwrap().as_str().parse().unwrap();
-                Hand::new(cards, bid)
-            })
-            .collect_vec();
-
-        let hands_debug = hands.iter().map(|h| format!("{:?}", h)).join("\n");
-        println!(
-            "Got {} Hands: ===========\n{}\n===========",
-            hands.len(),
-            hands_debug
-        );
-        Self { hands }
+    pub fn new(input_text: &str) -> Result<Self, ParseError> {
+        let hands: Vec<Hand> = input_text
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| Hand::parse(line).map_err(|e| e.on_line(i + 1)))
+            .try_collect()?;
+
+        if log::log_enabled!(log::Level::Trace) {
+            let hands_debug = hands.iter().map(|h| format!("{:?}", h)).join("\n");
+            log::trace!(
+                "Got {} Hands: ===========\n{}\n===========",
+                hands.len(),
+                hands_debug
+            );
+        }
+        Ok(Self { hands })
     }
 
     pub fn get_output(&self) -> i32 {
@@ -252,17 +216,50 @@ impl CardsManager {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::strategies::{hand_line, Rules};
+    use crate::{part_one, EXAMPLES};
+    use aoc_core::Part;
+    use proptest::prelude::*;
+
+    impl Rules for Hand {
+        fn parse(line: &str) -> Result<Self, ParseError> {
+            Hand::parse(line)
+        }
+
+        fn rank(&self) -> i32 {
+            self.hand_type.get_rank()
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn jokers_make_the_best_hand(line in hand_line()) {
+            let hand = Hand::parse(&line).unwrap();
+            // Jokers are always best spent all on the same card, so trying each one in their place
+            // gives the strongest type they could make
+            let labels: String = hand.cards.iter().map(Card::to_string).collect();
+            let best = "23456789TQKA"
+                .chars()
+                .map(|label| {
+                    let cards: Vec<_> = labels
+                        .replace('J', &label.to_string())
+                        .chars()
+                        .map(|c| part_one::Card::new(c).unwrap())
+                        .collect();
+                    part_one::Hand::get_type(&cards).get_rank()
+                })
+                .max()
+                .unwrap();
+            prop_assert_eq!(hand.hand_type.get_rank(), best);
+        }
+    }
 
     #[test]
     fn test_a() {
-        let input_text = "32T3K 765
-        T55J5 684
-        KK677 28
-        KTJJT 220
-        QQQJA 483";
-        let expected = 5905;
-
-        let cards = CardsManager::new(input_text);
+        let input_text = EXAMPLES.input("example");
+        let expected: i32 = EXAMPLES.answer("example", Part::Two);
+
+        let cards = CardsManager::new(input_text).unwrap();
         let sorted_hands = cards.hands.iter().sorted().collect_vec();
         let hands_debug = sorted_hands
             .iter()
@@ -294,16 +291,16 @@ mod tests {
     #[test]
     fn test_hand_type() {
         let input_expected_kind = vec![
-            (Hand::from_string("AAAAA"), HandType::FiveOfAKind),
-            (Hand::from_string("QQQQA"), HandType::FourOfAKind),
-            (Hand::from_string("QQQQJ"), HandType::FiveOfAKind),
-            (Hand::from_string("QQQJA"), HandType::FourOfAKind),
-            (Hand::from_string("QQQKA"), HandType::ThreeOfAKind),
-            (Hand::from_string("QQJAA"), HandType::FullHouse),
-            (Hand::from_string("555JJ"), HandType::FiveOfAKind),
-            (Hand::from_string("J5J5J"), HandType::FiveOfAKind),
-            (Hand::from_string("JJJJJ"), HandType::FiveOfAKind),
-            (Hand::from_string("JKKQQ"), HandType::FullHouse),
+            (Hand::from_string("AAAAA").unwrap(), HandType::FiveOfAKind),
+            (Hand::from_string("QQQQA").unwrap(), HandType::FourOfAKind),
+            (Hand::from_string("QQQQJ").unwrap(), HandType::FiveOfAKind),
+            (Hand::from_string("QQQJA").unwrap(), HandType::FourOfAKind),
+            (Hand::from_string("QQQKA").unwrap(), HandType::ThreeOfAKind),
+            (Hand::from_string("QQJAA").unwrap(), HandType::FullHouse),
+            (Hand::from_string("555JJ").unwrap(), HandType::FiveOfAKind),
+            (Hand::from_string("J5J5J").unwrap(), HandType::FiveOfAKind),
+            (Hand::from_string("JJJJJ").unwrap(), HandType::FiveOfAKind),
+            (Hand::from_string("JKKQQ").unwrap(), HandType::FullHouse),
         ];
 
         for (input, expected) in input_expected_kind {
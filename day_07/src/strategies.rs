@@ -0,0 +1,53 @@
+//! Made up hands for the property tests, and the properties that hold under either part's rules
+
+use crate::ParseError;
+use proptest::prelude::*;
+use std::fmt::{Debug, Display};
+
+/// What the properties need from either part's hands
+pub trait Rules: Ord + Display + Debug + Sized {
+    fn parse(line: &str) -> Result<Self, ParseError>;
+    /// The rank of the hand's type, which is higher for better types
+    fn rank(&self) -> i32;
+}
+
+/// A line of five cards from any of the labels, along with a bid
+pub fn hand_line() -> impl Strategy<Value = String> {
+    ("[2-9TJQKA]{5}", 1..1000i32).prop_map(|(labels, bid)| format!("{} {}", labels, bid))
+}
+
+fn parse<H: Rules>(line: &str) -> H {
+    H::parse(line).unwrap()
+}
+
+/// The labels of the hand's cards, without its bid
+fn labels(hand: &impl Display) -> String {
+    let hand = hand.to_string();
+    hand.split(' ').next().unwrap_or_default().to_string()
+}
+
+pub fn round_trip<H: Rules>(line: &str) -> Result<(), TestCaseError> {
+    prop_assert_eq!(parse::<H>(line).to_string(), line);
+    Ok(())
+}
+
+pub fn ordering_follows_rank<H: Rules>(a: &str, b: &str) -> Result<(), TestCaseError> {
+    let (a, b) = (parse::<H>(a), parse::<H>(b));
+    if a.rank() != b.rank() {
+        prop_assert_eq!(a.cmp(&b), a.rank().cmp(&b.rank()));
+    }
+    prop_assert_eq!(a.cmp(&b), b.cmp(&a).reverse());
+    prop_assert_eq!(a == b, labels(&a) == labels(&b));
+    prop_assert_eq!(a.cmp(&a), std::cmp::Ordering::Equal);
+    Ok(())
+}
+
+pub fn ordering_is_transitive<H: Rules>(lines: &[String]) -> Result<(), TestCaseError> {
+    let mut hands: Vec<H> = lines.iter().map(|line| parse(line)).collect();
+    hands.sort();
+    prop_assert!(hands[0] <= hands[1] && hands[1] <= hands[2] && hands[0] <= hands[2]);
+    prop_assert!(hands
+        .windows(2)
+        .all(|pair| pair[0].rank() <= pair[1].rank()));
+    Ok(())
+}
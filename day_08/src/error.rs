@@ -0,0 +1,14 @@
+use aoc_core::Location;
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ParseError {
+    #[error("{location}: expected a line of L and R steps")]
+    MissingSteps { location: Location, text: String },
+    #[error("{location}: '{text}' is not an L or R step")]
+    InvalidStep { location: Location, text: String },
+    #[error("{location}: '{text}' is not a `AAA = (BBB, CCC)` node")]
+    InvalidNode { location: Location, text: String },
+    #[error("{location}: '{text}' is never defined as a node")]
+    UnknownNode { location: Location, text: String },
+}
@@ -0,0 +1,112 @@
+//! Made up maps of the desert that look like the real ones
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+/// The number of starting nodes in the real inputs
+pub const SIZE: usize = 6;
+
+/// How many steps each ghost takes between end nodes, picked so the least common multiple of
+/// them is as large as the real ones
+const LOOPS: [usize; 13] = [41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97];
+/// How many left and right instructions there can be, which are prime in the real inputs
+const STEPS: [usize; 8] = [263, 269, 271, 277, 281, 283, 293, 307];
+
+/// The most starting nodes there are names for, since every node needs a three letter name of its
+/// own. It's the nodes along the loops that run out first, and there are enough of them for every
+/// loop length to come around nine times.
+pub const MAX_SIZE: usize = 9 * LOOPS.len();
+
+/// Makes up a map with `size` starting nodes, up to [`MAX_SIZE`], the first of which is `AAA`.
+/// Each one leads into its own loop with a single end node, two nodes wide so the instructions
+/// pick between them, but never off the loop. Like the real inputs, every end node leads where its
+/// start node does.
+pub fn generate(seed: u64, size: usize) -> String {
+    let size = size.min(MAX_SIZE);
+    let mut rng = StdRng::seed_from_u64(seed);
+    let steps: String = (0..*STEPS.choose(&mut rng).unwrap())
+        .map(|_| match rng.gen_bool(0.5) {
+            true => 'L',
+            false => 'R',
+        })
+        .collect();
+
+    // Each name is taken from a shuffled pool of the ones left, so none of them are used twice
+    let mut starts = names(&mut rng, |last| last == 'A', "AAA");
+    let mut ends = names(&mut rng, |last| last == 'Z', "ZZZ");
+    let mut middles = names(&mut rng, |last| !matches!(last, 'A' | 'Z'), "");
+    let take = |pool: &mut Vec<String>| pool.pop().expect("MAX_SIZE leaves enough names");
+
+    let mut loops = LOOPS.to_vec();
+    loops.shuffle(&mut rng);
+    let mut nodes = Vec::new();
+    for ghost in 0..size {
+        let (start, end) = match ghost {
+            0 => ("AAA".to_string(), "ZZZ".to_string()),
+            _ => (take(&mut starts), take(&mut ends)),
+        };
+        let layers: Vec<(String, String)> = (1..loops[ghost % loops.len()])
+            .map(|_| (take(&mut middles), take(&mut middles)))
+            .collect();
+
+        let first = &layers[0];
+        nodes.push(format!("{} = ({}, {})", start, first.0, first.1));
+        nodes.push(format!("{} = ({}, {})", end, first.0, first.1));
+        for (i, (left, right)) in layers.iter().enumerate() {
+            let (next_left, next_right) = match layers.get(i + 1) {
+                Some(next) => (next.0.as_str(), next.1.as_str()),
+                None => (end.as_str(), end.as_str()),
+            };
+            for node in [left, right] {
+                nodes.push(format!("{} = ({}, {})", node, next_left, next_right));
+            }
+        }
+    }
+    nodes.shuffle(&mut rng);
+    format!("{}\n\n{}\n", steps, nodes.join("\n"))
+}
+
+/// Every three letter name ending in a letter that `last` accepts, other than `except`, shuffled
+fn names(rng: &mut StdRng, last: impl Fn(char) -> bool, except: &str) -> Vec<String> {
+    let letters = || 'A'..='Z';
+    let mut names: Vec<String> = letters()
+        .flat_map(|a| letters().flat_map(move |b| letters().map(move |c| [a, b, c])))
+        .filter(|letters| last(letters[2]))
+        .map(|letters| letters.iter().collect())
+        .filter(|name: &String| name != except)
+        .collect();
+    names.shuffle(rng);
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day08;
+    use aoc_core::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(7, 3), generate(7, 3));
+        assert_ne!(generate(7, 3), generate(8, 3));
+
+        let input_text = generate(7, 3);
+        assert_eq!(input_text.lines().filter(|l| l.contains("A = ")).count(), 3);
+        let day = Day08::parse(&input_text).unwrap();
+        assert!(LOOPS.contains(&(day.part_one() as usize)));
+        assert_eq!(day.part_two() % day.part_one() as u64, 0);
+    }
+
+    #[test]
+    fn test_max_size() {
+        // The longest loops coming first still leaves enough names for the rest
+        let middles = 26 * 26 * 24;
+        let rounds = MAX_SIZE / LOOPS.len();
+        assert!(rounds * 2 * LOOPS.iter().map(|l| l - 1).sum::<usize>() <= middles);
+
+        let input_text = generate(7, MAX_SIZE);
+        let starts = input_text.lines().filter(|l| l.contains("A = ")).count();
+        assert_eq!(starts, MAX_SIZE);
+        assert!(Day08::parse(&input_text).is_ok());
+        assert_eq!(generate(7, MAX_SIZE + 1000), input_text);
+    }
+}
@@ -1,3 +1,182 @@
+use std::collections::HashSet;
+
+use aoc_core::{Location, Solution};
+use aoc_parse::{lines, node};
+use nom::{
+    bytes::complete::is_a,
+    character::complete::{line_ending, space0},
+    error::context,
+    multi::many0,
+    sequence::{pair, separated_pair, tuple},
+};
+
 pub mod part_one;
 
 pub mod part_two;
+
+mod error;
+pub use error::ParseError;
+
+/// The examples from the puzzle description, with their answers in `examples/answers.toml`
+pub const EXAMPLES: aoc_core::Examples = aoc_core::examples!["example_1", "example_2", "example_3"];
+
+pub mod generate;
+
+/// Made up inputs, for testing and benchmarking without the real ones
+pub const GENERATOR: aoc_core::Generator = aoc_core::Generator {
+    size: generate::SIZE,
+    generate: generate::generate,
+};
+
+#[derive(Debug)]
+pub struct Day08 {
+    part_one: part_one::StructA,
+    part_two: part_two::StructA,
+}
+
+impl Solution for Day08 {
+    type PartOne = i32;
+    type PartTwo = u64;
+    type Error = ParseError;
+
+    fn parse(input_text: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            part_one: part_one::StructA::new(input_text)?,
+            part_two: part_two::StructA::new(input_text)?,
+        })
+    }
+
+    fn part_one(&self) -> i32 {
+        self.part_one.get_output()
+    }
+
+    fn part_two(&self) -> u64 {
+        self.part_two.get_output()
+    }
+}
+
+/// A node of the network as `(name, left, right)`
+pub(crate) type Node<'a> = (&'a str, &'a str, &'a str);
+
+/// Splits the network into its line of steps and its nodes, checking every node it points to exists.
+/// The puzzle puts a blank line between the two, but the nodes can start on the very next line.
+pub(crate) fn parse_network(input_text: &str) -> Result<(&str, Vec<Node<'_>>), ParseError> {
+    let line_endings = tuple((
+        space0,
+        line_ending,
+        many0(pair(space0, line_ending)),
+        space0,
+    ));
+    let network = separated_pair(is_a("LR"), line_endings, lines(context("node", node)));
+    let (steps, nodes) = aoc_parse::parse(input_text, network).map_err(|e| match e.context() {
+        Some(node) => ParseError::InvalidNode {
+            location: node.location(input_text),
+            text: node.line().to_string(),
+        },
+        None => match e.input.chars().next() {
+            Some(c) if !c.is_whitespace() => ParseError::InvalidStep {
+                location: e.location(input_text),
+                text: c.to_string(),
+            },
+            _ => ParseError::MissingSteps {
+                location: e.location(input_text),
+                text: String::new(),
+            },
+        },
+    })?;
+    let nodes: Vec<Node> = nodes
+        .into_iter()
+        .map(|(name, (left, right))| (name, left, right))
+        .collect();
+
+    let names: HashSet<&str> = nodes.iter().map(|(name, _, _)| *name).collect();
+    for (_, left, right) in nodes.iter() {
+        if let Some(unknown) = [left, right].into_iter().find(|n| !names.contains(*n)) {
+            return Err(ParseError::UnknownNode {
+                location: Location::of(input_text, unknown),
+                text: unknown.to_string(),
+            });
+        }
+    }
+
+    Ok((steps, nodes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_network() {
+        let expected = (
+            "LLR",
+            vec![
+                ("AAA", "BBB", "BBB"),
+                ("BBB", "AAA", "ZZZ"),
+                ("ZZZ", "ZZZ", "ZZZ"),
+            ],
+        );
+        let input_text = "LLR
+
+        AAA = (BBB, BBB)
+        BBB = (AAA, ZZZ)
+        ZZZ = (ZZZ, ZZZ)";
+        assert_eq!(parse_network(input_text), Ok(expected.clone()));
+
+        let input_text = "LLR
+        AAA = (BBB, BBB)
+        BBB = (AAA, ZZZ)
+        ZZZ = (ZZZ, ZZZ)";
+        assert_eq!(parse_network(input_text), Ok(expected));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let input_text = "
+
+        AAA = (BBB, BBB)";
+        assert_eq!(
+            parse_network(input_text).unwrap_err(),
+            ParseError::MissingSteps {
+                location: Location::new(1, 1),
+                text: "".to_string(),
+            }
+        );
+
+        let input_text = "LLXR
+
+        AAA = (BBB, BBB)";
+        assert_eq!(
+            parse_network(input_text).unwrap_err(),
+            ParseError::InvalidStep {
+                location: Location::new(1, 3),
+                text: "X".to_string(),
+            }
+        );
+
+        let input_text = "LLR
+
+        AAA = (BBB, BBB)
+        BBB = AAA, ZZZ
+        ZZZ = (ZZZ, ZZZ)";
+        assert_eq!(
+            parse_network(input_text).unwrap_err(),
+            ParseError::InvalidNode {
+                location: Location::new(4, 9),
+                text: "BBB = AAA, ZZZ".to_string(),
+            }
+        );
+
+        let input_text = "LLR
+
+        AAA = (BBB, BBB)
+        BBB = (AAA, ZZZ)";
+        assert_eq!(
+            parse_network(input_text).unwrap_err(),
+            ParseError::UnknownNode {
+                location: Location::new(4, 21),
+                text: "ZZZ".to_string(),
+            }
+        );
+    }
+}
@@ -1,7 +1,8 @@
-use itertools::{multizip as zip, Itertools};
-use regex::RegexBuilder;
+use itertools::Itertools;
 use std::collections::HashMap;
 
+use crate::ParseError;
+
 #[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum Element {
     Start,
@@ -10,11 +11,11 @@ pub enum Element {
 }
 
 impl Element {
-    pub fn new(input: &str) -> Option<Self> {
+    pub fn new(input: &str) -> Self {
         match input {
-            "AAA" => Some(Self::Start),
-            "ZZZ" => Some(Self::End),
-            _ => Some(Self::Middle(input.to_string())),
+            "AAA" => Self::Start,
+            "ZZZ" => Self::End,
+            _ => Self::Middle(input.to_string()),
         }
     }
 }
@@ -32,89 +33,60 @@ pub struct StructA {
 }
 
 impl StructA {
-    pub fn new(input_text: &str) -> Self {
-        let re = RegexBuilder::new(r"(?<In>\w{3})\s= \((?<L>\w{3}), (?<R>\w{3})\)")
-            .multi_line(true)
-            .build()
-            .unwrap();
-        let steps = input_text
-            .lines()
-            .nth(0)
-            .unwrap()
+    pub fn new(input_text: &str) -> Result<Self, ParseError> {
+        let (steps, nodes) = crate::parse_network(input_text)?;
+        let steps = steps
             .chars()
-            .filter_map(|c| match c {
-                'L' => Some(Step::L),
-                'R' => Some(Step::R),
-                _ => None,
+            .map(|c| match c {
+                'L' => Step::L,
+                _ => Step::R,
             })
             .collect_vec();
 
-        let maps = re
-            .captures_iter(input_text)
-            .map(|captures| {
-                zip((
-                    captures.name("In").iter(),
-                    captures.name("L").iter(),
-                    captures.name("R").iter(),
-                ))
-                .map(|(input, left, right)| {
-                    (
-                        Element::new(input.as_str()).unwrap(),
-                        (
-                            Element::new(left.as_str()).unwrap(),
-                            Element::new(right.as_str()).unwrap(),
-                        ),
-                    )
-                })
-                .exactly_one()
-                .unwrap()
+        let maps = nodes
+            .into_iter()
+            .map(|(input, left, right)| {
+                (
+                    Element::new(input),
+                    (Element::new(left), Element::new(right)),
+                )
             })
-            .collect_vec();
-        let maps = HashMap::from_iter(maps);
-        Self { steps, maps }
+            .collect();
+        Ok(Self { steps, maps })
     }
 
-    fn take_step(&self, input: &Element, step_count: usize) -> Option<&Element> {
-        if input == &Element::End {
-            return None;
-        }
-        let step = &self.steps[step_count % self.steps.len()];
-        match step {
-            Step::L => Some(&self.maps[input].0),
-            Step::R => Some(&self.maps[input].1),
-        }
-    }
+    /// The number of steps from `AAA` to `ZZZ`, searching the nodes alongside how far through the
+    /// steps each one is reached, or -1 if it never gets there
     pub fn get_output(&self) -> i32 {
-        let step_count = 100000000;
-        let mut current = &Element::Start;
-        for step in 0..100000000 {
-            match self.take_step(current, step as usize) {
-                Some(new) => current = new,
-                None => return step,
-            }
+        let next = |&(element, step): &(&Element, usize)| {
+            let (left, right) = self.maps.get(element)?;
+            let element = match self.steps[step] {
+                Step::L => left,
+                Step::R => right,
+            };
+            Some((element, (step + 1) % self.steps.len()))
+        };
+        match aoc_graph::bfs((&Element::Start, 0), next, |(element, _)| {
+            **element == Element::End
+        }) {
+            Some(path) => path.len() as i32 - 1,
+            None => -1,
         }
-        step_count
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::EXAMPLES;
+    use aoc_core::Part;
 
     #[test]
     fn test_a() {
-        let input_text = "RL
-
-        AAA = (BBB, CCC)
-        BBB = (DDD, EEE)
-        CCC = (ZZZ, GGG)
-        DDD = (DDD, DDD)
-        EEE = (EEE, EEE)
-        GGG = (GGG, GGG)
-        ZZZ = (ZZZ, ZZZ)";
-        let expected = 2;
-
-        let struct_a = StructA::new(input_text);
+        let input_text = EXAMPLES.input("example_1");
+        let expected: i32 = EXAMPLES.answer("example_1", Part::One);
+
+        let struct_a = StructA::new(input_text).unwrap();
         println!("{:?}", struct_a);
         let actual = struct_a.get_output();
         println!("Got result of {}", actual);
@@ -124,17 +96,19 @@ mod tests {
 
     #[test]
     fn test_b() {
-        let input_text = "LLR
-
-        AAA = (BBB, BBB)
-        BBB = (AAA, ZZZ)
-        ZZZ = (ZZZ, ZZZ)";
-        let expected = 6;
+        let input_text = EXAMPLES.input("example_2");
+        let expected: i32 = EXAMPLES.answer("example_2", Part::One);
 
-        let struct_a = StructA::new(input_text);
+        let struct_a = StructA::new(input_text).unwrap();
         let actual = struct_a.get_output();
         println!("Got result of {}", actual);
 
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn test_never_ends() {
+        let input_text = "LR\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)";
+        assert_eq!(StructA::new(input_text).unwrap().get_output(), -1);
+    }
 }
@@ -1,8 +1,9 @@
-use itertools::{multizip as zip, Itertools};
-use num::integer;
-use regex::RegexBuilder;
+use aoc_cycle::find_goals;
+use itertools::Itertools;
 use std::collections::HashMap;
 
+use crate::ParseError;
+
 #[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum Element {
     Start(String),
@@ -11,27 +12,12 @@ pub enum Element {
 }
 
 impl Element {
-    pub fn new(input: &str) -> Option<Self> {
+    pub fn new(input: &str) -> Self {
         match input {
-            a if a.ends_with('A') => Some(Self::Start(input.to_string())),
-            z if z.ends_with('Z') => Some(Self::End(input.to_string())),
-            _ => Some(Self::Middle(input.to_string())),
-        }
-    }
-
-    pub fn get_steps(&self, parent: &StructA) -> u64 {
-        let mut first: u64 = 0;
-        let mut next = self;
-        for step in 0..u64::MAX {
-            next = parent.take_step(next, step as usize);
-            if let Element::End(_) = next {
-                match first {
-                    0 => first = step,
-                    _ => return step - first,
-                }
-            }
+            a if a.ends_with('A') => Self::Start(input.to_string()),
+            z if z.ends_with('Z') => Self::End(input.to_string()),
+            _ => Self::Middle(input.to_string()),
         }
-        u64::MAX
     }
 }
 
@@ -48,46 +34,26 @@ pub struct StructA {
 }
 
 impl StructA {
-    pub fn new(input_text: &str) -> Self {
-        let re = RegexBuilder::new(r"(?<In>\w{3})\s= \((?<L>\w{3}), (?<R>\w{3})\)")
-            .multi_line(true)
-            .build()
-            .unwrap();
-        let steps = input_text
-            .lines()
-            .nth(0)
-            .unwrap()
+    pub fn new(input_text: &str) -> Result<Self, ParseError> {
+        let (steps, nodes) = crate::parse_network(input_text)?;
+        let steps = steps
             .chars()
-            .filter_map(|c| match c {
-                'L' => Some(Step::L),
-                'R' => Some(Step::R),
-                _ => None,
+            .map(|c| match c {
+                'L' => Step::L,
+                _ => Step::R,
             })
             .collect_vec();
 
-        let maps = re
-            .captures_iter(input_text)
-            .map(|captures| {
-                zip((
-                    captures.name("In").iter(),
-                    captures.name("L").iter(),
-                    captures.name("R").iter(),
-                ))
-                .map(|(input, left, right)| {
-                    (
-                        Element::new(input.as_str()).unwrap(),
-                        (
-                            Element::new(left.as_str()).unwrap(),
-                            Element::new(right.as_str()).unwrap(),
-                        ),
-                    )
-                })
-                .exactly_one()
-                .unwrap()
+        let maps = nodes
+            .into_iter()
+            .map(|(input, left, right)| {
+                (
+                    Element::new(input),
+                    (Element::new(left), Element::new(right)),
+                )
             })
-            .collect_vec();
-        let maps = HashMap::from_iter(maps);
-        Self { steps, maps }
+            .collect();
+        Ok(Self { steps, maps })
     }
 
     pub fn take_step(&self, input: &Element, step_count: usize) -> &Element {
@@ -108,43 +74,60 @@ impl StructA {
             .collect_vec()
     }
 
+    /// Each ghost walks its own loop through the network, and might pass through any number of
+    /// end nodes on the way into it and around it, so this works out when each one is on an end
+    /// node and finds the first step they all are, or 0 if they never line up
     pub fn get_output(&self) -> u64 {
-        let starts = self.get_start();
-        let step_counts = starts
+        self.get_start()
             .into_iter()
-            .map(|element| element.get_steps(self))
-            .collect_vec();
-        let mut lcm = 1;
-        for step_count in step_counts {
-            lcm = integer::lcm(lcm, step_count);
-        }
-        lcm
+            .map(|start| {
+                find_goals(
+                    (start, 0),
+                    |&(element, step)| {
+                        let next = self.take_step(element, step);
+                        (next, (step + 1) % self.steps.len())
+                    },
+                    |(element, _)| matches!(element, Element::End(_)),
+                )
+            })
+            .reduce(|all, ghost| all.intersection(&ghost))
+            .and_then(|all| all.first())
+            .unwrap_or(0)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::EXAMPLES;
+    use aoc_core::Part;
 
     #[test]
     fn test_a() {
-        let input_text = "LR
-
-        11A = (11B, XXX)
-        11B = (XXX, 11Z)
-        11Z = (11B, XXX)
-        22A = (22B, XXX)
-        22B = (22C, 22C)
-        22C = (22Z, 22Z)
-        22Z = (22B, 22B)
-        XXX = (XXX, XXX)";
-        let expected = 6;
-
-        let struct_a = StructA::new(input_text);
+        let input_text = EXAMPLES.input("example_3");
+        let expected: u64 = EXAMPLES.answer("example_3", Part::Two);
+
+        let struct_a = StructA::new(input_text).unwrap();
         // println!("{:?}", struct_a);
         let actual = struct_a.get_output();
         println!("Got result of {}", actual);
 
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn test_uneven_loops() {
+        // The first ghost reaches 11Z after 2 steps then every 3 after, the second every 2 from 1
+        let input_text = "L
+
+11A = (11B, 11B)
+11B = (11Z, 11Z)
+11Z = (11C, 11C)
+11C = (11B, 11B)
+22A = (22Z, 22Z)
+22Z = (22A, 22A)";
+
+        let struct_a = StructA::new(input_text).unwrap();
+        assert_eq!(struct_a.get_output(), 5);
+    }
 }
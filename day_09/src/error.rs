@@ -0,0 +1,28 @@
+use aoc_core::{Located, Location};
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ParseError {
+    #[error("{location}: '{text}' is not a valid number")]
+    InvalidNumber { location: Location, text: String },
+    #[error("{location}: expected a history of at least one number")]
+    EmptySequence { location: Location, text: String },
+    #[error("{location}: '{text}' never settles into a sequence of zeros")]
+    NoPattern { location: Location, text: String },
+    #[error("{location}: '{text}' grows too large to extrapolate")]
+    TooLarge { location: Location, text: String },
+    #[error("{location}: '{text}' takes the total past what fits in an i32")]
+    TotalTooLarge { location: Location, text: String },
+}
+
+impl Located for ParseError {
+    fn location_mut(&mut self) -> &mut Location {
+        match self {
+            ParseError::InvalidNumber { location, .. }
+            | ParseError::EmptySequence { location, .. }
+            | ParseError::NoPattern { location, .. }
+            | ParseError::TooLarge { location, .. }
+            | ParseError::TotalTooLarge { location, .. } => location,
+        }
+    }
+}
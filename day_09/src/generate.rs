@@ -0,0 +1,60 @@
+//! Made up oasis reports that look like the real ones
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// The number of histories in the real inputs
+pub const SIZE: usize = 200;
+
+/// How many values each history has
+const LENGTH: i64 = 21;
+const MAX_DEGREE: usize = 8;
+
+/// Makes up `size` histories of 21 values, each following a polynomial of up to the 8th degree,
+/// so their differences always reach zero before running out
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut text = String::new();
+    for _ in 0..size {
+        // Written in terms of binomial coefficients, so integer coefficients give integer values
+        let degree = rng.gen_range(1..=MAX_DEGREE);
+        let coefficients: Vec<i64> = (0..=degree)
+            .map(|k| match k {
+                0 => rng.gen_range(-10..=30),
+                _ => rng.gen_range(-8..=8),
+            })
+            .collect();
+        let values: Vec<String> = (0..LENGTH)
+            .map(|n| {
+                let mut binomial = 1;
+                let mut value = 0;
+                for (k, coefficient) in coefficients.iter().enumerate() {
+                    value += coefficient * binomial;
+                    binomial = binomial * (n - k as i64) / (k as i64 + 1);
+                }
+                value.to_string()
+            })
+            .collect();
+        text += &values.join(" ");
+        text.push('\n');
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day09;
+    use aoc_core::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(7, 20), generate(7, 20));
+        assert_ne!(generate(7, 20), generate(8, 20));
+
+        let input_text = generate(7, 20);
+        assert!(input_text.lines().all(|line| line.split(' ').count() == 21));
+        let day = Day09::parse(&input_text).unwrap();
+        day.part_one();
+        day.part_two();
+    }
+}
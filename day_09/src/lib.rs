@@ -1,3 +1,183 @@
+use aoc_core::{Located, Location, Solution};
+use aoc_parse::signed_list;
+use itertools::Itertools;
+use nom::{character::complete::space0, sequence::preceded};
+use std::num::TryFromIntError;
+
 pub mod part_one;
 
 pub mod part_two;
+
+#[cfg(test)]
+mod strategies;
+
+mod error;
+pub use error::ParseError;
+
+/// The examples from the puzzle description, with their answers in `examples/answers.toml`
+pub const EXAMPLES: aoc_core::Examples = aoc_core::examples!["example"];
+
+pub mod generate;
+
+/// Made up inputs, for testing and benchmarking without the real ones
+pub const GENERATOR: aoc_core::Generator = aoc_core::Generator {
+    size: generate::SIZE,
+    generate: generate::generate,
+};
+
+#[derive(Debug)]
+pub struct Day09 {
+    part_one: part_one::StructA,
+    part_two: part_two::StructA,
+}
+
+impl Solution for Day09 {
+    type PartOne = i32;
+    type PartTwo = i32;
+    type Error = ParseError;
+
+    fn parse(input_text: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            part_one: part_one::StructA::new(input_text)?,
+            part_two: part_two::StructA::new(input_text)?,
+        })
+    }
+
+    fn part_one(&self) -> i32 {
+        self.part_one.get_output()
+    }
+
+    fn part_two(&self) -> i32 {
+        self.part_two.get_output()
+    }
+}
+
+/// Parses a single history line into its numbers
+pub(crate) fn parse_sequence(line: &str) -> Result<Vec<i32>, ParseError> {
+    aoc_parse::parse(line, preceded(space0, signed_list)).map_err(|e| {
+        match e.input.trim().is_empty() {
+            true => ParseError::EmptySequence {
+                location: Location::new(1, 1),
+                text: line.to_string(),
+            },
+            false => ParseError::InvalidNumber {
+                location: e.location(line),
+                text: e.token().to_string(),
+            },
+        }
+    })
+}
+
+/// A history along with the differences between its values, down to the sequence of zeros
+#[derive(Debug)]
+pub struct History {
+    sequences: Vec<Vec<i32>>,
+}
+
+impl History {
+    pub fn new(input_text: &str) -> Result<Self, ParseError> {
+        let sequence = parse_sequence(input_text)?;
+        let mut last = sequence.clone();
+        let mut sequences = vec![sequence];
+        while let Some(next) = Self::get_next(&last).map_err(|_| too_large(input_text))? {
+            last = next.clone();
+            sequences.push(next);
+        }
+
+        // Ran out of differences before they were all zero
+        if last.is_empty() {
+            return Err(ParseError::NoPattern {
+                location: Location::of(input_text, input_text.trim_start()),
+                text: input_text.trim().to_string(),
+            });
+        }
+        Ok(Self { sequences })
+    }
+
+    /// The differences between each pair of values, or `None` once they're all zero
+    ///
+    /// # Errors
+    ///
+    /// If one of the differences doesn't fit in an `i32`
+    pub fn get_next(last_sequence: &[i32]) -> Result<Option<Vec<i32>>, TryFromIntError> {
+        if last_sequence.iter().all(|i| *i == 0) {
+            return Ok(None);
+        }
+        last_sequence
+            .iter()
+            .tuple_windows()
+            .map(|(previous, current)| i32::try_from(*current as i64 - *previous as i64))
+            .collect::<Result<_, _>>()
+            .map(Some)
+    }
+
+    /// The history first, then each sequence of differences below it
+    pub fn sequences(&self) -> &[Vec<i32>] {
+        &self.sequences
+    }
+}
+
+/// Parses a history per line, checking each can be extrapolated with `extrapolate` and that
+/// they add up, so the parts can't overflow
+pub(crate) fn parse_histories(
+    input_text: &str,
+    extrapolate: fn(&History) -> Option<i32>,
+) -> Result<Vec<History>, ParseError> {
+    let mut total = 0i32;
+    input_text
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            let history = History::new(line).map_err(|e| e.on_line(i + 1))?;
+            let value = extrapolate(&history).ok_or_else(|| too_large(line).on_line(i + 1))?;
+            total = total.checked_add(value).ok_or_else(|| {
+                let error = ParseError::TotalTooLarge {
+                    location: Location::of(line, line.trim_start()),
+                    text: line.trim().to_string(),
+                };
+                error.on_line(i + 1)
+            })?;
+            Ok(history)
+        })
+        .try_collect()
+}
+
+/// Adds up what `extrapolate` makes of each history, or `None` if it overflows
+pub(crate) fn total(
+    histories: &[History],
+    extrapolate: fn(&History) -> Option<i32>,
+) -> Option<i32> {
+    histories.iter().try_fold(0i32, |total, history| {
+        total.checked_add(extrapolate(history)?)
+    })
+}
+
+fn too_large(line: &str) -> ParseError {
+    ParseError::TooLarge {
+        location: Location::of(line, line.trim_start()),
+        text: line.trim().to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use strategies::{polynomial, polynomial_history};
+
+    proptest! {
+        #[test]
+        fn extrapolates_polynomials((coefficients, line) in polynomial_history()) {
+            // Part one predicts the value after the last, and part two the one before the first
+            let length = line.split(' ').count() as i64;
+            let predictions = [
+                (length, part_one::extrapolate(&History::new(&line).unwrap()).unwrap()),
+                (-1, part_two::extrapolate(&History::new(&line).unwrap()).unwrap()),
+            ];
+            for (x, predicted) in predictions {
+                prop_assert_eq!(predicted as i64, polynomial(&coefficients, x));
+            }
+        }
+    }
+}
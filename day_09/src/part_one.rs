@@ -1,54 +1,12 @@
-use itertools::Itertools;
-use nom::ParseTo;
-// use regex::{Captures, RegexBuilder};
+use crate::{History, ParseError};
 
-#[derive(Debug)]
-pub struct History {
-    sequences: Vec<Vec<i32>>,
-}
-
-impl History {
-    pub fn new(input_text: &str) -> Self {
-        let sequence = input_text
-            .split_ascii_whitespace()
-            .filter_map(|f| f.parse_to())
-            .collect_vec();
-
-        let mut last = sequence.clone();
-        let mut sequences = vec![sequence.clone()];
-        while let Some(next) = Self::get_next(&last) {
-            last = next.clone();
-            sequences.push(next);
-        }
-        Self { sequences }
-    }
-
-    pub fn get_next(last_sequence: &Vec<i32>) -> Option<Vec<i32>> {
-        if last_sequence.iter().filter(|i| **i != 0).count() == 0 {
-            return None;
-        }
-        let mut next: Vec<i32> = vec![];
-        for i in 1..last_sequence.len() {
-            let previous = last_sequence[i - 1];
-            let current = last_sequence[i];
-            next.push(current - previous);
-        }
-        Some(next)
-    }
-
-    pub fn get_output(&self) -> i32 {
-        let size = self.sequences.len();
-        let mut last_last = 0;
-        for i in 0..size - 1 {
-            let this_sequence = &self.sequences[size - i - 1];
-            last_last += *this_sequence.last().unwrap();
-            // println!("{:?}, last last: {}", this_sequence, last_last);
-        }
-
-        let result = self.sequences.first().unwrap().last().unwrap() + last_last;
-        // println!("Result: {}", result);
-        result
-    }
+/// Extrapolates the next value, or `None` if it doesn't fit in an `i32`. Each sequence's next
+/// value is its last plus the next value of the one below it, so it's the sum of their lasts.
+pub fn extrapolate(history: &History) -> Option<i32> {
+    history
+        .sequences()
+        .iter()
+        .try_fold(0i32, |below, sequence| below.checked_add(*sequence.last()?))
 }
 
 #[derive(Debug)]
@@ -57,29 +15,30 @@ pub struct StructA {
 }
 
 impl StructA {
-    pub fn new(input_text: &str) -> Self {
-        let histories = input_text.lines().map(History::new).collect();
-        Self { histories }
+    pub fn new(input_text: &str) -> Result<Self, ParseError> {
+        let histories = crate::parse_histories(input_text, extrapolate)?;
+        Ok(Self { histories })
     }
 
     pub fn get_output(&self) -> i32 {
-        self.histories.iter().map(|h| h.get_output()).sum()
+        crate::total(&self.histories, extrapolate)
+            .expect("histories that overflow are rejected when parsed")
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::EXAMPLES;
+    use aoc_core::Location;
 
     #[test]
     fn test_a() {
-        let input_text = "0 3 6 9 12 15
-        1 3 6 10 15 21
-        10 13 16 21 30 45";
+        let input_text = EXAMPLES.input("example");
         let expected = [18, 28, 68];
         let expected_total = expected.into_iter().sum::<i32>();
 
-        let struct_a = StructA::new(input_text);
+        let struct_a = StructA::new(input_text).unwrap();
         let actual = struct_a.get_output();
         println!("Got result of {}", actual);
 
@@ -88,10 +47,63 @@ mod tests {
             .iter()
             .zip(expected)
             .for_each(|(actual, expected)| {
-                let history_output = actual.get_output();
+                let history_output = extrapolate(actual).unwrap();
                 println!("History {:?} result of {}", actual, history_output);
                 assert_eq!(expected, history_output);
             });
         assert_eq!(expected_total, actual);
     }
+
+    #[test]
+    fn test_parse_errors() {
+        let input_text = "0 3 6 9 12 15
+        1 3 6 1O 15 21";
+        assert_eq!(
+            StructA::new(input_text).unwrap_err(),
+            ParseError::InvalidNumber {
+                location: Location::new(2, 15),
+                text: "1O".to_string(),
+            }
+        );
+
+        let input_text = "0 3 6 9 12 15
+        1 2 4 8 16";
+        assert_eq!(
+            StructA::new(input_text).unwrap_err(),
+            ParseError::NoPattern {
+                location: Location::new(2, 9),
+                text: "1 2 4 8 16".to_string(),
+            }
+        );
+
+        let input_text = "0 3 6 9 12 15
+        -2000000000 2000000000 0";
+        assert_eq!(
+            StructA::new(input_text).unwrap_err(),
+            ParseError::TooLarge {
+                location: Location::new(2, 9),
+                text: "-2000000000 2000000000 0".to_string(),
+            }
+        );
+
+        let input_text = "2147483645 2147483646 2147483647";
+        assert_eq!(
+            StructA::new(input_text).unwrap_err(),
+            ParseError::TooLarge {
+                location: Location::new(1, 1),
+                text: "2147483645 2147483646 2147483647".to_string(),
+            }
+        );
+
+        let input_text = "2000000000 2000000000
+        1 2 3
+        2000000000 2000000000";
+        assert_eq!(
+            StructA::new(input_text).unwrap_err(),
+            ParseError::TotalTooLarge {
+                location: Location::new(3, 9),
+                text: "2000000000 2000000000".to_string(),
+            }
+        );
+    }
 }
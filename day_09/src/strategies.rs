@@ -0,0 +1,22 @@
+//! Made up histories for the property tests of both parts
+
+use itertools::Itertools;
+use proptest::prelude::*;
+
+/// The value at `x` of the polynomial with the given coefficients, lowest power first
+pub fn polynomial(coefficients: &[i64], x: i64) -> i64 {
+    coefficients.iter().rev().fold(0, |value, c| value * x + c)
+}
+
+/// The coefficients of a small polynomial, and a line of its values from `x = 0` on. A
+/// polynomial of degree d needs d + 2 values for its differences to reach zero, so there are
+/// always at least that many.
+pub fn polynomial_history() -> impl Strategy<Value = (Vec<i64>, String)> {
+    (proptest::collection::vec(-5..=5i64, 1..=6), 1..=10usize).prop_map(|(coefficients, extra)| {
+        let length = (coefficients.len() + extra) as i64;
+        let line = (0..length)
+            .map(|x| polynomial(&coefficients, x).to_string())
+            .join(" ");
+        (coefficients, line)
+    })
+}
@@ -0,0 +1,57 @@
+//! Made up pipe mazes that look like the real ones
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// The width and height of the real inputs
+pub const SIZE: usize = 140;
+
+/// The tiles that fill the maze around the loop
+const TILES: &[u8] = b"|-LJ7F.";
+
+/// Makes up a `size` by `size` maze of random tiles, with a rectangular loop through it that starts
+/// at `S` in its top left corner
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut rows: Vec<Vec<u8>> = (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| TILES[rng.gen_range(0..TILES.len())])
+                .collect()
+        })
+        .collect();
+
+    if size >= 2 {
+        let top = rng.gen_range(0..size - 1);
+        let left = rng.gen_range(0..size - 1);
+        let bottom = rng.gen_range(top + 1..size);
+        let right = rng.gen_range(left + 1..size);
+        for row in rows.iter_mut().take(bottom).skip(top + 1) {
+            row[left] = b'|';
+            row[right] = b'|';
+        }
+        rows[top][left + 1..right].fill(b'-');
+        rows[bottom][left + 1..right].fill(b'-');
+        rows[top][left] = b'S';
+        rows[top][right] = b'7';
+        rows[bottom][left] = b'L';
+        rows[bottom][right] = b'J';
+    }
+
+    rows.into_iter()
+        .map(|row| String::from_utf8(row).expect("the tiles are ASCII") + "\n")
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(7, 20), generate(7, 20));
+        let maze = generate(7, 20);
+        assert_eq!(maze.lines().count(), 20);
+        assert!(maze.lines().all(|line| line.len() == 20));
+        assert_eq!(maze.matches('S').count(), 1);
+    }
+}
@@ -0,0 +1,40 @@
+//! Made up images of galaxies that look like the real ones
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// The width and height of the real inputs
+pub const SIZE: usize = 140;
+
+/// Roughly how many of the tiles are galaxies
+const DENSITY: f64 = 0.02;
+
+/// Makes up a `size` by `size` image with galaxies scattered through it, leaving about one in ten
+/// rows and columns empty for the universe to expand into
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let empty_rows: Vec<bool> = (0..size).map(|_| rng.gen_bool(0.1)).collect();
+    let empty_columns: Vec<bool> = (0..size).map(|_| rng.gen_bool(0.1)).collect();
+    let mut text = String::new();
+    for empty_row in empty_rows {
+        for &empty_column in empty_columns.iter() {
+            let galaxy = !empty_row && !empty_column && rng.gen_bool(DENSITY);
+            text.push(if galaxy { '#' } else { '.' });
+        }
+        text.push('\n');
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(7, 20), generate(7, 20));
+        let image = generate(7, 20);
+        assert_eq!(image.lines().count(), 20);
+        assert!(image.lines().all(|line| line.len() == 20));
+        assert!(image.chars().all(|c| matches!(c, '#' | '.' | '\n')));
+    }
+}
@@ -1,3 +1,59 @@
-pub mod part_one;
+use aoc_core::Solution;
 
-pub mod part_two;
+mod error;
+pub use error::ParseError;
+
+/// The examples from the puzzle description, with their answers in `examples/answers.toml`
+pub const EXAMPLES: aoc_core::Examples = aoc_core::examples!["example"];
+
+pub mod generate;
+
+/// Made up inputs, for testing and benchmarking without the real ones
+pub const GENERATOR: aoc_core::Generator = aoc_core::Generator {
+    size: generate::SIZE,
+    generate: generate::generate,
+};
+
+/// Not solved yet, so parsing always fails with [`ParseError::NotSolved`] and there's never one to
+/// answer
+#[derive(Debug)]
+pub enum Day11 {}
+
+impl Solution for Day11 {
+    type PartOne = i64;
+    type PartTwo = i64;
+    type Error = ParseError;
+
+    fn parse(_input_text: &str) -> Result<Self, ParseError> {
+        Err(ParseError::NotSolved)
+    }
+
+    fn part_one(&self) -> i64 {
+        match *self {}
+    }
+
+    fn part_two(&self) -> i64 {
+        match *self {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_not_solved() {
+        let error = Day11::parse(EXAMPLES.input("example")).unwrap_err();
+        assert_eq!(error, ParseError::NotSolved);
+        assert_eq!(error.to_string(), "day 11 isn't solved yet");
+    }
+
+    #[test]
+    #[ignore = "day 11 isn't solved yet"]
+    fn test_a() {
+        let expected: i64 = 374;
+
+        let actual = Day11::parse(EXAMPLES.input("example")).map(|day| day.part_one());
+        assert_eq!(Ok(expected), actual);
+    }
+}
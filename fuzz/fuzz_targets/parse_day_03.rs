@@ -0,0 +1,9 @@
+#![no_main]
+
+use aoc_core::Solution;
+use libfuzzer_sys::fuzz_target;
+
+// Any input can be rejected with an error, but parsing it must never panic or hang
+fuzz_target!(|input_text: &str| {
+    let _ = day_03::Day03::parse(input_text);
+});